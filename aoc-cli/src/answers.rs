@@ -0,0 +1,60 @@
+use aoc::examples::Expectations;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Accepted answers keyed by day directory, then by input file name.
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, Expectations>>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("cannot read `{}`: {error}", path.display()))?;
+
+        Self::parse(&content).map_err(|error| format!("cannot parse `{}`: {error}", path.display()))
+    }
+
+    fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// Accepted answers for `file` in the directory of `day`.
+    pub fn get(&self, day: u8, file: &str) -> Option<&Expectations> {
+        self.0.get(&format!("day-{day:02}"))?.get(file)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::{Expected, Part};
+
+    const ANSWERS: &str = r#"
+[day-05."input.txt"]
+part1 = "CMZ"
+
+[day-07."other.txt"]
+part1 = 95437
+part2 = 24933642
+"#;
+
+    #[test]
+    fn test_get() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        let day_05 = answers.get(5, "input.txt").unwrap();
+
+        assert_eq!(
+            Some(&Expected::Text("CMZ".to_string())),
+            day_05.get(Part::One)
+        );
+        assert_eq!(None, day_05.get(Part::Two));
+        assert_eq!(
+            Some(&Expected::Number(24933642)),
+            answers.get(7, "other.txt").unwrap().get(Part::Two)
+        );
+        assert!(answers.get(7, "input.txt").is_none());
+    }
+}
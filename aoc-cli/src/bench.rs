@@ -0,0 +1,165 @@
+use crate::days;
+use crate::BenchArgs;
+use aoc::bench::{BenchOptions, BenchReport, Phase};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// Median timing of every day and phase from an earlier run.
+#[derive(Debug, Default, PartialEq)]
+struct Baseline(BTreeMap<(u8, Phase), Duration>);
+
+impl Baseline {
+    fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| format!("cannot read baseline `{}`: {error}", path.display()))?;
+
+        Self::parse(&content)
+            .map_err(|error| format!("cannot parse baseline `{}`: {error}", path.display()))
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+            .map(|(i, line)| {
+                let fields: Vec<&str> = line.split('\t').collect();
+                let [day, phase, median] = fields[..] else {
+                    return Err(format!("line {}: expected 3 fields", i + 1));
+                };
+                let day = day
+                    .parse()
+                    .map_err(|_| format!("line {}: invalid day `{day}`", i + 1))?;
+                let phase = phase
+                    .parse()
+                    .map_err(|error| format!("line {}: {error}", i + 1))?;
+                let median = median
+                    .parse()
+                    .map_err(|_| format!("line {}: invalid median `{median}`", i + 1))?;
+
+                Ok(((day, phase), Duration::from_nanos(median)))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn render(&self) -> String {
+        let mut content = String::from("# day\tphase\tmedian_ns\n");
+
+        for ((day, phase), median) in &self.0 {
+            writeln!(content, "{day}\t{phase}\t{}", median.as_nanos()).unwrap();
+        }
+
+        content
+    }
+}
+
+/// Benchmarks each selected day, optionally saving or comparing a baseline.
+pub fn bench(args: &BenchArgs) -> Result<(), String> {
+    let selected = days::select(args.day)?;
+    let options = BenchOptions {
+        warmup: args.warmup,
+        samples: args.samples,
+    };
+    let previous = args.compare.as_deref().map(Baseline::load).transpose()?;
+    let config = days::config()?;
+    let mut baseline = Baseline::default();
+
+    for (i, day) in selected.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        let input = args
+            .input
+            .source()
+            .read(day.dir())
+            .map_err(|error| error.to_string())?;
+        let params = args.input.params(day, &config)?;
+        let report = day
+            .puzzle
+            .bench(&input, &params, &options)
+            .map_err(|error| format!("day {:02}: invalid input at {error}", day.puzzle.day()))?;
+
+        println!("Day {:02}: {}", report.day, day.puzzle.title());
+        print_report(&report, previous.as_ref());
+
+        for (phase, samples) in &report.phases {
+            baseline.0.insert((report.day, *phase), samples.median());
+        }
+    }
+
+    if let Some(path) = &args.save {
+        std::fs::write(path, baseline.render())
+            .map_err(|error| format!("cannot write baseline `{}`: {error}", path.display()))?;
+        println!("\nbaseline saved to {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn print_report(report: &BenchReport, previous: Option<&Baseline>) {
+    for (phase, samples) in &report.phases {
+        let mut line = format!(
+            "  {phase:<6} min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+            samples.min(),
+            samples.median(),
+            samples.max()
+        );
+
+        if let Some(old) = previous.and_then(|baseline| baseline.0.get(&(report.day, *phase))) {
+            write!(line, "  {}", change(*old, samples.median())).unwrap();
+        }
+
+        println!("{line}");
+    }
+}
+
+/// Describes how the median moved relative to the baseline.
+fn change(old: Duration, new: Duration) -> String {
+    if old.is_zero() {
+        return "(no baseline timing)".to_string();
+    }
+
+    let percent = (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+
+    format!("({percent:+.1}% vs {old:.2?})")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::Part;
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let baseline = Baseline(BTreeMap::from([
+            ((7, Phase::Parse), Duration::from_nanos(1_500)),
+            ((15, Phase::Solve(Part::Two)), Duration::from_millis(700)),
+        ]));
+
+        assert_eq!(Ok(&baseline), Baseline::parse(&baseline.render()).as_ref());
+    }
+
+    #[test]
+    fn test_baseline_errors() {
+        assert!(Baseline::parse("7\tparse").is_err());
+        assert!(Baseline::parse("7\tpart3\t100").is_err());
+    }
+
+    #[test]
+    fn test_change() {
+        let old = Duration::from_millis(100);
+
+        assert_eq!(
+            "(+10.0% vs 100.00ms)",
+            change(old, Duration::from_millis(110))
+        );
+        assert_eq!(
+            "(-50.0% vs 100.00ms)",
+            change(old, Duration::from_millis(50))
+        );
+    }
+}
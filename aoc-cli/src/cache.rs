@@ -0,0 +1,135 @@
+use crate::days::WORKSPACE_DIR;
+use aoc::{Answer, Part};
+use serde_json::{json, Value};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Directory of the cached answers, unless `AOC_CACHE_DIR` names another.
+const CACHE_DIR: &str = "target/aoc-cache";
+
+/// Answers of earlier runs, one file per day, part, input and build, so a
+/// changed input or a rebuilt binary never sees a stale answer.
+pub struct Cache {
+    dir: PathBuf,
+    build: u64,
+}
+
+impl Cache {
+    /// The cache of the running binary, which a rebuild gives a new size or
+    /// modification time.
+    pub fn open() -> Result<Self, String> {
+        let exe = std::env::current_exe()
+            .map_err(|error| format!("cannot locate the running binary: {error}"))?;
+        let metadata = fs::metadata(&exe)
+            .map_err(|error| format!("cannot read {}: {error}", exe.display()))?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .unwrap_or_default();
+        let build = format!(
+            "{}\n{}\n{}",
+            exe.display(),
+            metadata.len(),
+            modified.as_nanos()
+        );
+
+        Ok(Self::at(dir(), hash(build.as_bytes())))
+    }
+
+    fn at(dir: PathBuf, build: u64) -> Self {
+        Self { dir, build }
+    }
+
+    fn path(&self, day: u8, part: Part, input: u64) -> PathBuf {
+        self.dir.join(format!(
+            "day-{day:02}-part{part}-{input:016x}-{:016x}.json",
+            self.build
+        ))
+    }
+
+    /// The answer cached for `part` of `day` on the input hashing to
+    /// `input`. Unreadable entries count as missing.
+    pub fn get(&self, day: u8, part: Part, input: u64) -> Option<Answer> {
+        let content = fs::read_to_string(self.path(day, part, input)).ok()?;
+
+        Answer::from_json(
+            serde_json::from_str::<Value>(&content)
+                .ok()?
+                .get("answer")?,
+        )
+    }
+
+    pub fn put(&self, day: u8, part: Part, input: u64, answer: &Answer) -> Result<(), String> {
+        let answer = json!({ "answer": answer.to_json() });
+
+        aoc::files::write_atomic(&self.path(day, part, input), &answer.to_string())
+    }
+}
+
+fn dir() -> PathBuf {
+    match std::env::var_os("AOC_CACHE_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(WORKSPACE_DIR).join(CACHE_DIR),
+    }
+}
+
+/// FNV-1a, which unlike the hasher of the standard library is guaranteed
+/// to give the same hash in every build.
+pub fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Removes every cached answer and reports how many there were.
+pub fn clear() -> Result<(), String> {
+    let dir = dir();
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries.count(),
+        Err(error) if error.kind() == ErrorKind::NotFound => 0,
+        Err(error) => return Err(format!("cannot read {}: {error}", dir.display())),
+    };
+
+    if entries > 0 {
+        fs::remove_dir_all(&dir)
+            .map_err(|error| format!("cannot remove {}: {error}", dir.display()))?;
+    }
+    println!("Removed {entries} cached answers from {}", dir.display());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cache() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::at(dir.clone(), 1);
+        let input = hash(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+
+        assert_eq!(None, cache.get(6, Part::One, input));
+        cache.put(6, Part::One, input, &Answer::Number(7)).unwrap();
+        cache
+            .put(6, Part::Two, input, &Answer::from("#.\n.#"))
+            .unwrap();
+
+        assert_eq!(Some(Answer::Number(7)), cache.get(6, Part::One, input));
+        assert_eq!(Some(Answer::from("#.\n.#")), cache.get(6, Part::Two, input));
+        assert_eq!(None, cache.get(6, Part::One, hash(b"other\n")));
+        assert_eq!(None, Cache::at(dir.clone(), 2).get(6, Part::One, input));
+        assert_eq!(None, cache.get(7, Part::One, input));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(0xcbf2_9ce4_8422_2325, hash(b""));
+        assert_eq!(0xaf63_dc4c_8601_ec8c, hash(b"a"));
+    }
+}
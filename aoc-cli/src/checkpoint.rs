@@ -0,0 +1,54 @@
+use crate::cache;
+use crate::days::WORKSPACE_DIR;
+use aoc::checkpoint::Checkpoint;
+use aoc::params::Params;
+use aoc::Part;
+use std::path::Path;
+use std::time::Duration;
+
+/// Directory of the snapshots written by `run --checkpoint`.
+const CHECKPOINT_DIR: &str = "target/aoc-checkpoints";
+
+/// Whether `run` snapshots the long simulations and resumes them, with one
+/// snapshot per day and part.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Checkpoints {
+    pub every: Option<Duration>,
+    pub resume: bool,
+}
+
+impl Checkpoints {
+    pub fn is_off(&self) -> bool {
+        self.every.is_none() && !self.resume
+    }
+
+    /// The checkpoint of `part` of `day`, whose snapshot only resumes the
+    /// same `input` read with the same `params`.
+    pub fn get(&self, day: u8, part: Part, input: &str, params: &Params) -> Option<Checkpoint> {
+        if self.is_off() {
+            return None;
+        }
+
+        Some(Checkpoint {
+            path: Path::new(WORKSPACE_DIR)
+                .join(CHECKPOINT_DIR)
+                .join(format!("day-{day:02}-part{part}.json")),
+            key: format!(
+                "{:016x}",
+                cache::hash(format!("{params}\n{input}").as_bytes())
+            ),
+            every: self.every,
+            resume: self.resume,
+        })
+    }
+}
+
+/// Reads the seconds between two snapshots.
+pub fn parse_interval(arg: &str) -> Result<Duration, String> {
+    match arg.parse::<f64>() {
+        Ok(seconds) if seconds.is_finite() && seconds >= 0.0 => {
+            Ok(Duration::from_secs_f64(seconds))
+        }
+        _ => Err(format!("invalid interval `{arg}`, expected seconds")),
+    }
+}
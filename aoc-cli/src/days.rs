@@ -0,0 +1,241 @@
+use crate::checkpoint::Checkpoints;
+use aoc::cancel::{self, TimedOut, Timeouts};
+use aoc::checkpoint;
+use aoc::explain::{self, Explanation};
+use aoc::generate::Generator;
+use aoc::params::{Config, Params, CONFIG_FILE};
+use aoc::visual::Visualize;
+use aoc::{Entry, InputSource, ParseError, Part, Puzzle, Report};
+use std::path::Path;
+use std::time::Duration;
+
+/// Root of the workspace, where `answers.toml` lives.
+pub const WORKSPACE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// A registered day together with the directory holding its inputs.
+pub struct Day {
+    pub puzzle: &'static dyn Puzzle,
+    pub generator: Generator,
+    /// Plays the day's simulation frame by frame, for days that have one.
+    pub visualize: Option<Visualize>,
+    dir: &'static str,
+}
+
+impl Day {
+    pub fn dir(&self) -> &'static Path {
+        Path::new(self.dir)
+    }
+
+    /// Parameters for reading `source`, from `config` and then `overrides`.
+    pub fn params(
+        &self,
+        config: &Config,
+        source: &InputSource,
+        overrides: &[(String, i64)],
+    ) -> Result<Params, String> {
+        config.params(
+            self.puzzle.params(),
+            self.puzzle.day(),
+            self.dir(),
+            source,
+            overrides,
+        )
+    }
+
+    /// Solves `parts` as `options` say, one at a time if any of them needs
+    /// it.
+    pub fn run(
+        &self,
+        input: &str,
+        params: &Params,
+        parts: &[Part],
+        options: &RunOptions,
+    ) -> Result<Run, ParseError> {
+        let day = self.puzzle.day();
+        let RunOptions {
+            timeouts,
+            checkpoints,
+            explain,
+        } = options;
+
+        if !explain
+            && checkpoints.is_off()
+            && parts.iter().all(|&part| timeouts.get(day, part).is_none())
+        {
+            return Ok(Run {
+                report: self.puzzle.run(input, params, parts)?,
+                timed_out: vec![],
+                explanations: vec![],
+            });
+        }
+
+        let mut run = Run {
+            report: Report {
+                day,
+                parse: Duration::ZERO,
+                parts: vec![],
+            },
+            timed_out: vec![],
+            explanations: vec![],
+        };
+
+        for &part in parts {
+            let checkpoint = checkpoints.get(day, part, input, params);
+            let solve = || self.puzzle.run(input, params, &[part]);
+
+            match cancel::with_timeout(timeouts.get(day, part), || {
+                checkpoint::with_checkpoint(checkpoint, || match explain {
+                    true => explain::explain(solve),
+                    false => (solve(), Explanation::default()),
+                })
+            }) {
+                Ok(((solved, explanation), error)) => {
+                    if let Some(error) = error {
+                        eprintln!("warning: day {day:02} part {part}: {error}");
+                    }
+                    let solved = solved?;
+                    // Each part parses the input again, only time it once
+                    if run.report.parts.is_empty() {
+                        run.report.parse = solved.parse;
+                    }
+                    run.report.parts.extend(solved.parts);
+                    if *explain {
+                        run.explanations.push((part, explanation));
+                    }
+                }
+                Err(error) => run.timed_out.push((part, error)),
+            }
+        }
+
+        Ok(run)
+    }
+}
+
+/// How [`Day::run`] solves each part.
+#[derive(Default)]
+pub struct RunOptions {
+    pub timeouts: Timeouts,
+    pub checkpoints: Checkpoints,
+    /// Collect the notes each part takes, see [`aoc::explain`].
+    pub explain: bool,
+}
+
+/// The answers of [`Day::run`], with the parts that ran out of time and
+/// the explanation of each part when asked for.
+pub struct Run {
+    pub report: Report,
+    pub timed_out: Vec<(Part, TimedOut)>,
+    pub explanations: Vec<(Part, Explanation)>,
+}
+
+/// Parameters from the `aoc.toml` at the root of the workspace.
+pub fn config() -> Result<Config, String> {
+    Config::load(&Path::new(WORKSPACE_DIR).join(CONFIG_FILE))
+}
+
+pub static DAYS: &[Day] = &[
+    Day {
+        puzzle: &Entry::<day_01::Day01>::new(),
+        generator: day_01::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-01"),
+    },
+    Day {
+        puzzle: &Entry::<day_02::Day02>::new(),
+        generator: day_02::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-02"),
+    },
+    Day {
+        puzzle: &Entry::<day_03::Day03>::new(),
+        generator: day_03::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-03"),
+    },
+    Day {
+        puzzle: &Entry::<day_04::Day04>::new(),
+        generator: day_04::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-04"),
+    },
+    Day {
+        puzzle: &Entry::<day_05::Day05>::new(),
+        generator: day_05::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-05"),
+    },
+    Day {
+        puzzle: &Entry::<day_06::Day06>::new(),
+        generator: day_06::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-06"),
+    },
+    Day {
+        puzzle: &Entry::<day_07::Day07>::new(),
+        generator: day_07::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-07"),
+    },
+    Day {
+        puzzle: &Entry::<day_08::Day08>::new(),
+        generator: day_08::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-08"),
+    },
+    Day {
+        puzzle: &Entry::<day_09::Day09>::new(),
+        generator: day_09::generate::GENERATOR,
+        visualize: Some(day_09::visual::visualize),
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-09"),
+    },
+    Day {
+        puzzle: &Entry::<day_10::Day10>::new(),
+        generator: day_10::generate::GENERATOR,
+        visualize: Some(day_10::visual::visualize),
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-10"),
+    },
+    Day {
+        puzzle: &Entry::<day_11::Day11>::new(),
+        generator: day_11::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-11"),
+    },
+    Day {
+        puzzle: &Entry::<day_12::Day12>::new(),
+        generator: day_12::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-12"),
+    },
+    Day {
+        puzzle: &Entry::<day_13::Day13>::new(),
+        generator: day_13::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-13"),
+    },
+    Day {
+        puzzle: &Entry::<day_14::Day14>::new(),
+        generator: day_14::generate::GENERATOR,
+        visualize: Some(day_14::visual::visualize),
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-14"),
+    },
+    Day {
+        puzzle: &Entry::<day_15::Day15>::new(),
+        generator: day_15::generate::GENERATOR,
+        visualize: None,
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../day-15"),
+    },
+];
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.puzzle.day() == day)
+}
+
+/// Resolves a day given on the command line, or every day when `None`.
+pub fn select(day: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    match day {
+        Some(number) => Ok(vec![
+            find(number).ok_or(format!("day {number} is not available"))?
+        ]),
+        None => Ok(DAYS.iter().collect()),
+    }
+}
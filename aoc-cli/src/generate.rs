@@ -0,0 +1,76 @@
+use crate::days;
+use crate::GenerateArgs;
+use aoc::generate::Knob;
+use std::fs;
+
+/// Writes a generated input for the selected day, or lists its knobs.
+pub fn generate(args: &GenerateArgs) -> Result<(), String> {
+    let day = days::find(args.day).ok_or(format!("day {} is not available", args.day))?;
+
+    if args.list_knobs {
+        print_knobs(day.generator.knobs);
+        return Ok(());
+    }
+
+    let overrides = args
+        .knobs
+        .iter()
+        .map(|(name, value)| (name.as_str(), *value))
+        .collect::<Vec<_>>();
+    let input = day
+        .generator
+        .run(args.seed, &overrides)
+        .map_err(|error| format!("day {:02}: {error}", args.day))?;
+
+    match &args.output {
+        Some(path) => fs::write(path, input)
+            .map_err(|error| format!("cannot write {}: {error}", path.display())),
+        None => {
+            print!("{input}");
+            Ok(())
+        }
+    }
+}
+
+fn print_knobs(knobs: &[Knob]) {
+    let width = knobs.iter().map(|knob| knob.name.len()).max().unwrap_or(0);
+
+    for knob in knobs {
+        println!(
+            "  {:<width$}  {:>10}  {:>16}  {}",
+            knob.name,
+            knob.default,
+            format!("{}..={}", knob.range.start(), knob.range.end()),
+            knob.help
+        );
+    }
+}
+
+/// Parses a `--knob NAME=VALUE` argument.
+pub fn parse_knob(arg: &str) -> Result<(String, usize), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or(format!("expected `NAME=VALUE`, found `{arg}`"))?;
+    let value = value
+        .replace('_', "")
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for knob `{name}`"))?;
+
+    Ok((name.to_string(), value))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_knob() {
+        assert_eq!(Ok(("stacks".to_string(), 9)), parse_knob("stacks=9"));
+        assert_eq!(
+            Ok(("area".to_string(), 4_000_000)),
+            parse_knob("area=4_000_000")
+        );
+        assert!(parse_knob("stacks").is_err());
+        assert!(parse_knob("stacks=-1").is_err());
+    }
+}
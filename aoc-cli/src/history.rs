@@ -0,0 +1,113 @@
+use crate::days::WORKSPACE_DIR;
+use aoc::{Part, PartReport};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File `run` appends every part it solves on a bundled input to.
+const HISTORY_FILE: &str = "target/aoc-history.jsonl";
+
+/// One part solved by `run`, as a line of the history file.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+    pub day: u8,
+    pub part: u8,
+    /// The answer as written by [`aoc::Answer::to_json`].
+    pub answer: Value,
+    pub ns: u64,
+}
+
+impl Record {
+    pub fn part(&self) -> Option<Part> {
+        Part::ALL
+            .into_iter()
+            .find(|part| part.number() == self.part)
+    }
+}
+
+pub fn path() -> PathBuf {
+    Path::new(WORKSPACE_DIR).join(HISTORY_FILE)
+}
+
+/// Appends a record for each of `parts` of `day` to the file at `path`.
+pub fn record(path: &Path, day: u8, parts: &[PartReport]) -> Result<(), String> {
+    let at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    let mut lines = String::new();
+
+    for part in parts {
+        let record = Record {
+            at,
+            day,
+            part: part.part.number(),
+            answer: part.answer.to_json(),
+            ns: part.elapsed.as_nanos() as u64,
+        };
+        lines += &serde_json::to_string(&record).map_err(|error| error.to_string())?;
+        lines.push('\n');
+    }
+
+    let error = |error| format!("cannot write {}: {error}", path.display());
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(error)?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(lines.as_bytes()))
+        .map_err(error)
+}
+
+/// Every record in the file at `path`, oldest first, skipping lines that
+/// cannot be read. A missing file has no records.
+pub fn load(path: &Path) -> Result<Vec<Record>, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(content
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(vec![]),
+        Err(error) => Err(format!("cannot read {}: {error}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::Answer;
+    use std::time::Duration;
+
+    #[test]
+    fn test_record() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-history-{}", std::process::id()))
+            .join("history.jsonl");
+        let part = |part, answer: Answer| PartReport {
+            part,
+            answer,
+            elapsed: Duration::from_nanos(42),
+        };
+
+        assert_eq!(Ok(vec![]), load(&path));
+        record(&path, 10, &[part(Part::One, Answer::Number(13140))]).unwrap();
+        record(&path, 10, &[part(Part::Two, Answer::from("#.\n.#"))]).unwrap();
+
+        let records = load(&path).unwrap();
+        assert_eq!(2, records.len());
+        assert_eq!(Some(Part::Two), records[1].part());
+        assert_eq!(
+            Some(Answer::from("#.\n.#")),
+            Answer::from_json(&records[1].answer)
+        );
+        assert_eq!(42, records[0].ns);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,306 @@
+mod answers;
+mod bench;
+mod cache;
+mod checkpoint;
+mod days;
+mod generate;
+mod history;
+mod new;
+mod pool;
+mod profile;
+mod run;
+mod serve;
+mod verify;
+mod visualize;
+
+use aoc::params::{Config, Params};
+use aoc::{Format, InputSource, Part};
+use clap::{Args, Parser, Subcommand};
+use days::Day;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[cfg(feature = "profile")]
+#[global_allocator]
+static ALLOCATOR: aoc::alloc::Counting = aoc::alloc::Counting;
+
+/// Runs the Advent of Code 2022 solutions.
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print trace events from the solvers on stderr: -v for milestones,
+    /// -vv for steps, -vvv for every operation
+    #[arg(short = 'v', action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Only trace these days or modules, such as `7` or `day_12::generate`
+    #[arg(long, value_name = "MODULE", value_delimiter = ',', global = true)]
+    trace_filter: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with --all
+    Run(RunArgs),
+    /// List the available days
+    List,
+    /// Check every answer against the accepted ones in answers.toml
+    Verify(VerifyArgs),
+    /// Time parsing and both parts over repeated runs
+    Bench(BenchArgs),
+    /// Count the allocations and peak memory of parsing and both parts, in
+    /// a build with `--features profile`
+    Profile(ProfileArgs),
+    /// Create the crate for a new day from a template
+    New(NewArgs),
+    /// Write a random input for a day, sized by its knobs
+    Generate(GenerateArgs),
+    /// Watch a day's simulation step by step, or record its frames
+    Visualize(VisualizeArgs),
+    /// Manage the answers cached by `run`
+    #[command(subcommand)]
+    Cache(CacheCommand),
+    /// Serve a dashboard of the latest answers and timings on localhost
+    Serve(ServeArgs),
+}
+
+#[derive(Args)]
+struct ServeArgs {
+    /// Port to listen on, on 127.0.0.1 only
+    #[arg(long, default_value_t = 7878)]
+    port: u16,
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Remove every cached answer
+    Clear,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve
+    #[arg(required_unless_present = "all")]
+    day: Option<u8>,
+    /// Solve every available day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Only solve this part (1 or 2)
+    #[arg(long)]
+    part: Option<Part>,
+    /// Print the answers as `text` or `json`
+    #[arg(long, default_value = "text")]
+    format: Format,
+    /// Solve every part again instead of reusing cached answers
+    #[arg(long)]
+    no_cache: bool,
+    /// Solve the days on this many threads and print them as a table
+    #[arg(long, value_name = "N", requires = "all")]
+    jobs: Option<NonZeroUsize>,
+    /// Give up on a part after this many seconds, for every part, a day
+    /// such as `14=10` or a part such as `15.2=30`; may be repeated
+    #[arg(long, value_name = "[DAY[.PART]=]SECONDS")]
+    timeout: Vec<String>,
+    /// Snapshot long simulations at most this often, to resume them later
+    #[arg(long, value_name = "SECONDS", value_parser = checkpoint::parse_interval)]
+    checkpoint: Option<Duration>,
+    /// Resume long simulations from the snapshots of an earlier run
+    #[arg(long)]
+    resume: bool,
+    /// Show the reasoning behind each answer, for the days that record it
+    #[arg(long, conflicts_with = "jobs")]
+    explain: bool,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// Where to read the input from; shared by commands that accept `--all`.
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from this file instead, or from stdin with `-`
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["all", "test"])]
+    input: Option<String>,
+    /// Use the bundled examples/test-input.txt instead of input.txt
+    #[arg(long)]
+    test: bool,
+    /// Set a puzzle parameter over aoc.toml, may be repeated
+    #[arg(
+        long = "param",
+        value_name = "NAME=VALUE",
+        value_parser = aoc::params::parse_override,
+        conflicts_with = "all"
+    )]
+    params: Vec<(String, i64)>,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        input_source(self.input.as_deref(), self.test)
+    }
+
+    /// Parameters of `day` for this input, see [`aoc::params`].
+    fn params(&self, day: &Day, config: &Config) -> Result<Params, String> {
+        day.params(config, &self.source(), &self.params)
+    }
+}
+
+fn input_source(input: Option<&str>, test: bool) -> InputSource {
+    match input {
+        Some(arg) => InputSource::from_arg(arg),
+        None if test => InputSource::Test,
+        None => InputSource::Bundled,
+    }
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day
+    day: Option<u8>,
+    /// Verify every example against its sidecar instead of input.txt
+    #[arg(long)]
+    test: bool,
+    /// Give up on a part after this many seconds, for every part, a day
+    /// such as `14=10` or a part such as `15.2=30`; may be repeated
+    #[arg(long, value_name = "[DAY[.PART]=]SECONDS")]
+    timeout: Vec<String>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark
+    #[arg(required_unless_present = "all")]
+    day: Option<u8>,
+    /// Benchmark every available day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    /// Untimed runs of each phase before sampling
+    #[arg(long, default_value_t = 1)]
+    warmup: usize,
+    /// Timed runs of each phase
+    #[arg(long, default_value_t = 10)]
+    samples: usize,
+    /// Write the median timings to this file
+    #[arg(long, value_name = "PATH")]
+    save: Option<PathBuf>,
+    /// Compare the median timings with a file written by --save
+    #[arg(long, value_name = "PATH")]
+    compare: Option<PathBuf>,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Args)]
+struct ProfileArgs {
+    /// Day to profile
+    #[arg(required_unless_present = "all")]
+    day: Option<u8>,
+    /// Profile every available day
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    day: u8,
+    /// Puzzle title
+    #[arg(long, default_value = "TODO")]
+    title: String,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day to generate an input for
+    day: u8,
+    /// Seed of the random numbers; the same seed gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Set a size knob, may be repeated
+    #[arg(long = "knob", value_name = "NAME=VALUE", value_parser = generate::parse_knob)]
+    knobs: Vec<(String, usize)>,
+    /// Write the input to this file instead of stdout
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+    /// List the knobs of the day and their defaults instead
+    #[arg(long, conflicts_with_all = ["knobs", "output"])]
+    list_knobs: bool,
+}
+
+#[derive(Args)]
+struct VisualizeArgs {
+    /// Day to visualize
+    day: u8,
+    /// Part whose simulation to show (1 or 2)
+    #[arg(long, default_value = "1")]
+    part: Part,
+    /// Frames per second to start playing at
+    #[arg(long, default_value_t = 30)]
+    fps: u32,
+    /// Write the frames to this file instead of playing them
+    #[arg(long, value_name = "PATH")]
+    headless: Option<PathBuf>,
+    /// Only write every Nth frame with --headless; the last is always written
+    #[arg(long, default_value_t = 1, requires = "headless")]
+    every: usize,
+    /// Read the input from this file instead, or from stdin with `-`
+    #[arg(long, value_name = "PATH", conflicts_with = "test")]
+    input: Option<String>,
+    /// Use the bundled examples/test-input.txt instead of input.txt
+    #[arg(long)]
+    test: bool,
+}
+
+impl VisualizeArgs {
+    fn source(&self) -> InputSource {
+        input_source(self.input.as_deref(), self.test)
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    if let Err(message) = aoc::trace::init(cli.verbose, &cli.trace_filter) {
+        eprintln!("error: {message}");
+        return ExitCode::from(2);
+    }
+
+    let result = match cli.command {
+        Command::Run(args) => run::run(&args),
+        Command::List => {
+            run::list();
+            Ok(())
+        }
+        Command::Verify(args) => verify::verify(&args),
+        Command::Bench(args) => bench::bench(&args),
+        Command::Profile(args) => profile::profile(&args),
+        Command::New(args) => new::new(&args),
+        Command::Generate(args) => generate::generate(&args),
+        Command::Visualize(args) => visualize::visualize(&args),
+        Command::Cache(CacheCommand::Clear) => cache::clear(),
+        Command::Serve(args) => serve::serve(&args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+    }
+}
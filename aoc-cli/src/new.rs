@@ -0,0 +1,112 @@
+use crate::days::WORKSPACE_DIR;
+use crate::NewArgs;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Files of a new day, relative to its directory, and their templates.
+const FILES: [(&str, &str); 7] = [
+    (
+        "Cargo.toml",
+        include_str!("../templates/Cargo.toml.template"),
+    ),
+    ("src/main.rs", include_str!("../templates/main.rs.template")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.template")),
+    (
+        "src/generate.rs",
+        include_str!("../templates/generate.rs.template"),
+    ),
+    ("input.txt", ""),
+    ("examples/test-input.txt", ""),
+    (
+        "examples/test-input.toml",
+        include_str!("../templates/test-input.toml.template"),
+    ),
+];
+
+/// Creates `day-NN/` in the workspace from the templates.
+pub fn new(args: &NewArgs) -> Result<(), String> {
+    scaffold(Path::new(WORKSPACE_DIR), args.day, &args.title)?;
+    let name = format!("day-{:02}", args.day);
+
+    println!("created {name}/");
+    println!();
+    println!("to run it with `aoc`, add `{name} = {{ path = \"../{name}\" }}` to");
+    println!("aoc-cli/Cargo.toml and an entry for it to DAYS in aoc-cli/src/days.rs");
+
+    Ok(())
+}
+
+/// Writes the files of day `day` under `root`, refusing to touch a day that
+/// already exists.
+fn scaffold(root: &Path, day: u8, title: &str) -> Result<PathBuf, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {day} is not between 1 and 25"));
+    }
+
+    let dir = root.join(format!("day-{day:02}"));
+    let render = |template: &str| {
+        template
+            .replace("{{DAY}}", &format!("{day:02}"))
+            .replace("{{NUMBER}}", &day.to_string())
+            .replace(
+                "{{TITLE}}",
+                &title.replace('\\', "\\\\").replace('"', "\\\""),
+            )
+    };
+
+    // `create_dir` fails if the day exists, so nothing is ever overwritten
+    fs::create_dir(&dir).map_err(|error| match error.kind() {
+        std::io::ErrorKind::AlreadyExists => format!("day-{day:02} already exists"),
+        _ => format!("cannot create {}: {error}", dir.display()),
+    })?;
+    for subdir in ["src", aoc::examples::EXAMPLES_DIR] {
+        fs::create_dir(dir.join(subdir))
+            .map_err(|error| format!("cannot create {}/{subdir}: {error}", dir.display()))?;
+    }
+
+    for (file, template) in FILES {
+        let path = dir.join(file);
+
+        fs::write(&path, render(template))
+            .map_err(|error| format!("cannot write {}: {error}", path.display()))?;
+    }
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+
+        let dir = scaffold(&root, 16, "Proboscidea \"Volcanium\"").unwrap();
+        let lib = fs::read_to_string(dir.join("src/lib.rs")).unwrap();
+
+        assert!(lib.contains("pub struct Day16;"));
+        assert!(lib.contains("const DAY: u8 = 16;"));
+        assert!(lib.contains("pub mod generate;"));
+        assert!(dir.join("src/generate.rs").exists());
+        assert!(lib.contains(r#"const TITLE: &'static str = "Proboscidea \"Volcanium\"";"#));
+        assert!(fs::read_to_string(dir.join("Cargo.toml"))
+            .unwrap()
+            .contains(r#"name = "day-16""#));
+        assert_eq!(
+            "",
+            fs::read_to_string(dir.join("examples/test-input.txt")).unwrap()
+        );
+        assert!(fs::read_to_string(dir.join("examples/test-input.toml"))
+            .unwrap()
+            .contains("part1 = 0"));
+
+        fs::write(dir.join("input.txt"), "mine").unwrap();
+        assert!(scaffold(&root, 16, "Again").is_err());
+        assert_eq!("mine", fs::read_to_string(dir.join("input.txt")).unwrap());
+        assert!(scaffold(&root, 26, "Too late").is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
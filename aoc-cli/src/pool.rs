@@ -0,0 +1,154 @@
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+/// What happened to one of the jobs, by its index.
+pub enum Event<R> {
+    Started(usize),
+    /// The result of the job, or the message it panicked with.
+    Finished(usize, Result<R, String>),
+}
+
+thread_local! {
+    /// Whether this thread is running a job.
+    static IN_JOB: Cell<bool> = const { Cell::new(false) };
+    /// Message and location of the last panic of a job on this thread.
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo) + Sync + Send>;
+
+/// Keeps the panics of jobs for their events instead of printing them over
+/// the output, and hands any other panic to the previous hook, which it
+/// puts back when dropped.
+struct QuietJobs(Option<Arc<PanicHook>>);
+
+impl QuietJobs {
+    fn install() -> Self {
+        let previous = Arc::new(panic::take_hook());
+        let hook = previous.clone();
+
+        panic::set_hook(Box::new(move |info| {
+            if !IN_JOB.get() {
+                return hook(info);
+            }
+            let message = info.payload_as_str().unwrap_or("panicked");
+            let message = match info.location() {
+                Some(location) => format!("{message} at {location}"),
+                None => message.to_string(),
+            };
+            PANIC.with(|panic| *panic.borrow_mut() = Some(message));
+        }));
+
+        Self(Some(previous))
+    }
+}
+
+impl Drop for QuietJobs {
+    fn drop(&mut self) {
+        // Dropping our hook releases its handle on the previous one
+        drop(panic::take_hook());
+        match self.0.take().map(Arc::try_unwrap) {
+            Some(Ok(previous)) => panic::set_hook(previous),
+            Some(Err(previous)) => panic::set_hook(Box::new(move |info| previous(info))),
+            None => {}
+        }
+    }
+}
+
+fn run_job<R>(job: impl FnOnce() -> R) -> Result<R, String> {
+    IN_JOB.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(job));
+    IN_JOB.set(false);
+
+    result.map_err(|_| {
+        PANIC
+            .with(|panic| panic.borrow_mut().take())
+            .unwrap_or_else(|| "panicked".to_string())
+    })
+}
+
+/// Runs `job` on this thread, returning the message it panicked with, if
+/// it did, instead of printing it.
+pub fn catch<R>(job: impl FnOnce() -> R) -> Result<R, String> {
+    let _quiet = QuietJobs::install();
+    run_job(job)
+}
+
+/// Runs `work` on each of `items` over `jobs` threads, taking them in
+/// order, and calls `on_event` on the calling thread as each job starts and
+/// finishes. A job that panics finishes with its message instead of taking
+/// the other jobs down with it.
+pub fn run<T: Sync, R: Send>(
+    jobs: usize,
+    items: &[T],
+    work: impl Fn(&T) -> R + Sync,
+    mut on_event: impl FnMut(Event<R>),
+) {
+    let _quiet = QuietJobs::install();
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+
+                let _ = sender.send(Event::Started(i));
+                let _ = sender.send(Event::Finished(i, run_job(|| work(item))));
+            });
+        }
+        drop(sender);
+
+        receiver.iter().for_each(&mut on_event);
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_run() {
+        let items = (1..=20).collect::<Vec<u64>>();
+        let mut started = 0;
+        let mut results = vec![None; items.len()];
+
+        run(
+            4,
+            &items,
+            |&n| {
+                assert_ne!(7, n, "seven");
+                n * n
+            },
+            |event| match event {
+                Event::Started(_) => started += 1,
+                Event::Finished(i, result) => results[i] = Some(result),
+            },
+        );
+
+        assert_eq!(20, started);
+        assert_eq!(Some(Ok(36)), results[5]);
+        let panicked = results[6].clone().unwrap().unwrap_err();
+        assert!(panicked.contains("seven"), "{panicked}");
+        assert!(panicked.contains("pool.rs"), "{panicked}");
+        assert!(results.iter().all(Option::is_some));
+    }
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(Ok(4), catch(|| 2 + 2));
+
+        let panicked = catch(|| panic!("eight")).unwrap_err();
+        assert!(panicked.contains("eight"), "{panicked}");
+        assert!(panicked.contains("pool.rs"), "{panicked}");
+    }
+}
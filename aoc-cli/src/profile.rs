@@ -0,0 +1,80 @@
+use crate::days;
+use crate::ProfileArgs;
+use aoc::alloc::AllocReport;
+
+/// Counts the allocations of each selected day.
+pub fn profile(args: &ProfileArgs) -> Result<(), String> {
+    if !cfg!(feature = "profile") {
+        return Err(
+            "allocations are only counted in a build with `--features profile`".to_string(),
+        );
+    }
+
+    let selected = days::select(args.day)?;
+    let config = days::config()?;
+
+    for (i, day) in selected.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        let input = args
+            .input
+            .source()
+            .read(day.dir())
+            .map_err(|error| error.to_string())?;
+        let params = args.input.params(day, &config)?;
+        let report = day
+            .puzzle
+            .profile(&input, &params)
+            .map_err(|error| format!("day {:02}: invalid input at {error}", day.puzzle.day()))?;
+
+        println!("Day {:02}: {}", report.day, day.puzzle.title());
+        print_report(&report);
+    }
+
+    Ok(())
+}
+
+fn print_report(report: &AllocReport) {
+    for (phase, allocations) in &report.phases {
+        println!(
+            "  {phase:<6} allocations {:>10}  total {:>10}  peak {:>10}",
+            allocations.count,
+            bytes(allocations.bytes),
+            bytes(allocations.peak)
+        );
+    }
+}
+
+/// Formats `bytes` with a binary unit.
+fn bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!("0 B", bytes(0));
+        assert_eq!("1023 B", bytes(1023));
+        assert_eq!("1.5 KiB", bytes(1536));
+        assert_eq!("3.0 MiB", bytes(3 << 20));
+    }
+}
@@ -0,0 +1,492 @@
+use crate::cache::{self, Cache};
+use crate::checkpoint::Checkpoints;
+use crate::days::{self, Day, Run, RunOptions, DAYS};
+use crate::history;
+use crate::pool::{self, Event};
+use crate::RunArgs;
+use aoc::cancel::TimedOut;
+use aoc::explain::Explanation;
+use aoc::params::{Config, Params};
+use aoc::{Answer, Format, InputSource, Part, PartReport, Report};
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+use std::io::IsTerminal;
+use std::time::{Duration, Instant};
+
+pub fn list() {
+    for day in DAYS {
+        println!("Day {:02}  {}", day.puzzle.day(), day.puzzle.title());
+    }
+}
+
+/// Everything `run` needs to solve any of the selected days.
+struct Runner<'a> {
+    args: &'a RunArgs,
+    parts: Vec<Part>,
+    source: InputSource,
+    config: Config,
+    options: RunOptions,
+    cache: Option<Cache>,
+}
+
+/// A day's answers, with the parts that came from the cache, those that
+/// ran out of time and the explanations asked for.
+struct Solved {
+    report: Report,
+    cached: Vec<Part>,
+    timed_out: Vec<(Part, TimedOut)>,
+    explanations: Vec<(Part, Explanation)>,
+}
+
+impl Runner<'_> {
+    /// Reads the input of `day` and solves it.
+    fn run_day(&self, day: &Day) -> Result<Solved, String> {
+        let input = self
+            .source
+            .read(day.dir())
+            .map_err(|error| error.to_string())?;
+        let params = self.args.input.params(day, &self.config)?;
+        let solved = solve(
+            day,
+            &input,
+            &params,
+            &self.parts,
+            &self.options,
+            self.cache.as_ref(),
+        )?;
+
+        // The dashboard shows how the real inputs went over time
+        if self.source == InputSource::Bundled && self.args.input.params.is_empty() {
+            let (report, cached) = (&solved.report, &solved.cached);
+            let solved = report
+                .parts
+                .iter()
+                .filter(|part| !cached.contains(&part.part))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if !solved.is_empty() {
+                if let Err(error) = history::record(&history::path(), report.day, &solved) {
+                    eprintln!("warning: {error}");
+                }
+            }
+        }
+
+        Ok(solved)
+    }
+}
+
+pub fn run(args: &RunArgs) -> Result<(), String> {
+    let selected = days::select(args.day)?;
+    let config = days::config()?;
+    let mut timeouts = config.timeouts.clone();
+    for timeout in &args.timeout {
+        timeouts.set(timeout)?;
+    }
+    let runner = Runner {
+        args,
+        parts: match args.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        },
+        source: args.input.source(),
+        config,
+        options: RunOptions {
+            timeouts,
+            checkpoints: Checkpoints {
+                every: args.checkpoint,
+                resume: args.resume,
+            },
+            explain: args.explain,
+        },
+        // Traces and explanations only come from solvers that actually run
+        cache: match args.no_cache || args.explain || aoc::trace::active() {
+            true => None,
+            false => Cache::open()
+                .inspect_err(|error| eprintln!("warning: not caching answers: {error}"))
+                .ok(),
+        },
+    };
+
+    if let Some(jobs) = args.jobs {
+        return run_parallel(&runner, &selected, jobs.get());
+    }
+
+    let mut reports = Vec::new();
+    let mut timed_out = 0;
+    let mut failed = 0;
+
+    for (i, day) in selected.iter().enumerate() {
+        // With `--all`, a day that fails does not hide the ones after it
+        let outcome = match args.all {
+            true => match pool::catch(|| runner.run_day(day)) {
+                Ok(Ok(solved)) => Outcome::Solved(solved),
+                Ok(Err(error)) => Outcome::Failed(error),
+                Err(message) => Outcome::Panicked(message),
+            },
+            false => Outcome::Solved(runner.run_day(day)?),
+        };
+        match &outcome {
+            Outcome::Solved(solved) => timed_out += solved.timed_out.len(),
+            _ => failed += 1,
+        }
+
+        match args.format {
+            Format::Text if i > 0 => {
+                println!();
+                print_outcome(day, &outcome);
+            }
+            Format::Text => print_outcome(day, &outcome),
+            Format::Json => reports.push(outcome_to_json(day, &outcome)),
+        }
+    }
+
+    // A single day prints its object, `--all` prints them as one array.
+    if args.format == Format::Json {
+        let output = if args.all {
+            Value::Array(reports)
+        } else {
+            reports.remove(0)
+        };
+        println!("{output}");
+    }
+
+    match (failed, timed_out) {
+        (0, 0) => Ok(()),
+        (0, 1) => Err("1 part timed out".to_string()),
+        (0, timed_out) => Err(format!("{timed_out} parts timed out")),
+        (1, _) => Err("1 day failed".to_string()),
+        (failed, _) => Err(format!("{failed} days failed")),
+    }
+}
+
+/// How one day of a run over every day ended.
+enum Outcome {
+    Solved(Solved),
+    Failed(String),
+    Panicked(String),
+}
+
+/// Solves the selected days over `jobs` threads, showing which ones are
+/// still running, then prints them all as a table, or as a JSON array.
+fn run_parallel(runner: &Runner, selected: &[&'static Day], jobs: usize) -> Result<(), String> {
+    let start = Instant::now();
+    let mut outcomes = selected.iter().map(|_| None).collect::<Vec<_>>();
+    let mut running = BTreeSet::new();
+    let progress = std::io::stderr().is_terminal();
+
+    pool::run(
+        jobs,
+        selected,
+        |day| runner.run_day(day),
+        |event| {
+            match event {
+                Event::Started(i) => {
+                    running.insert(selected[i].puzzle.day());
+                }
+                Event::Finished(i, result) => {
+                    running.remove(&selected[i].puzzle.day());
+                    outcomes[i] = Some(match result {
+                        Ok(Ok(solved)) => Outcome::Solved(solved),
+                        Ok(Err(error)) => Outcome::Failed(error),
+                        Err(message) => Outcome::Panicked(message),
+                    });
+                }
+            }
+
+            if progress {
+                let done = outcomes.iter().filter(|outcome| outcome.is_some()).count();
+                let days = running
+                    .iter()
+                    .map(|day| format!("day {day:02}"))
+                    .collect::<Vec<_>>();
+                eprint!(
+                    "\r\x1b[K[{done:>2}/{}] running {}",
+                    selected.len(),
+                    days.join(", ")
+                );
+            }
+        },
+    );
+    if progress {
+        eprint!("\r\x1b[K");
+    }
+
+    let outcomes = outcomes.into_iter().flatten().collect::<Vec<_>>();
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| match outcome {
+            Outcome::Solved(solved) => !solved.timed_out.is_empty(),
+            _ => true,
+        })
+        .count();
+
+    match runner.args.format {
+        Format::Text => {
+            print_table(selected, &outcomes);
+            println!(
+                "\n{} days in {:.2?} on {jobs} threads, {failed} failed",
+                selected.len(),
+                start.elapsed()
+            );
+        }
+        Format::Json => {
+            let reports = selected
+                .iter()
+                .zip(&outcomes)
+                .map(|(day, outcome)| outcome_to_json(day, outcome))
+                .collect();
+            println!("{}", Value::Array(reports));
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        1 => Err("1 day failed".to_string()),
+        failed => Err(format!("{failed} days failed")),
+    }
+}
+
+fn outcome_to_json(day: &Day, outcome: &Outcome) -> Value {
+    match outcome {
+        Outcome::Solved(solved) => report_to_json(solved),
+        Outcome::Failed(error) => {
+            json!({ "day": day.puzzle.day(), "status": "failed", "error": error })
+        }
+        Outcome::Panicked(message) => {
+            json!({ "day": day.puzzle.day(), "status": "panicked", "error": message })
+        }
+    }
+}
+
+/// One row per day: its answers, how long it took and how it ended.
+fn print_table(selected: &[&'static Day], outcomes: &[Outcome]) {
+    println!(
+        "{:<4} {:<20} {:<20} {:>10}  Status",
+        "Day", "Part 1", "Part 2", "Time"
+    );
+
+    for (day, outcome) in selected.iter().zip(outcomes) {
+        let (answers, time, status) = match outcome {
+            Outcome::Solved(Solved {
+                report,
+                cached,
+                timed_out,
+                ..
+            }) => {
+                let answer = |part| {
+                    let answer = report.parts.iter().find(|report| report.part == part);
+                    match answer {
+                        Some(report) => cell(&report.answer),
+                        None if timed_out.iter().any(|&(other, _)| other == part) => {
+                            "timed out".to_string()
+                        }
+                        None => "-".to_string(),
+                    }
+                };
+                let elapsed = report.parse
+                    + report
+                        .parts
+                        .iter()
+                        .map(|part| part.elapsed)
+                        .sum::<Duration>();
+                let status = match timed_out.first() {
+                    Some((part, error)) => format!("part {part} {error}"),
+                    None if cached.len() == report.parts.len() => "cached".to_string(),
+                    None => "ok".to_string(),
+                };
+
+                (
+                    [answer(Part::One), answer(Part::Two)],
+                    format!("{elapsed:.2?}"),
+                    status,
+                )
+            }
+            Outcome::Failed(error) => (
+                Default::default(),
+                "-".to_string(),
+                format!("failed: {error}"),
+            ),
+            Outcome::Panicked(message) => (
+                Default::default(),
+                "-".to_string(),
+                format!("panicked: {message}"),
+            ),
+        };
+        let [part1, part2] = answers.map(|answer| match answer.is_empty() {
+            true => "-".to_string(),
+            false => answer,
+        });
+
+        println!(
+            "{:<4} {part1:<20} {part2:<20} {time:>10}  {status}",
+            format!("{:02}", day.puzzle.day())
+        );
+    }
+}
+
+/// An answer short enough for a table cell.
+fn cell(answer: &Answer) -> String {
+    let answer = answer.to_string();
+
+    match answer.lines().count() {
+        0 | 1 if answer.chars().count() <= 20 => answer,
+        0 | 1 => answer.chars().take(19).chain(['…']).collect(),
+        lines => format!("<{lines} lines>"),
+    }
+}
+
+/// Solves `parts` of `day` as `options` say, taking what it can from
+/// `cache` and storing the rest there.
+fn solve(
+    day: &Day,
+    input: &str,
+    params: &Params,
+    parts: &[Part],
+    options: &RunOptions,
+    cache: Option<&Cache>,
+) -> Result<Solved, String> {
+    let number = day.puzzle.day();
+    // The same input asks another question with other parameters
+    let key = cache::hash(format!("{params}\n{input}").as_bytes());
+    let cached = parts
+        .iter()
+        .filter_map(|&part| Some((part, cache?.get(number, part, key)?)))
+        .collect::<Vec<_>>();
+    let missing = parts
+        .iter()
+        .copied()
+        .filter(|part| cached.iter().all(|(other, _)| other != part))
+        .collect::<Vec<_>>();
+
+    let Run {
+        mut report,
+        timed_out,
+        explanations,
+    } = match missing.is_empty() {
+        true => Run {
+            report: Report {
+                day: number,
+                parse: Duration::ZERO,
+                parts: vec![],
+            },
+            timed_out: vec![],
+            explanations: vec![],
+        },
+        false => day
+            .run(input, params, &missing, options)
+            .map_err(|error| format!("day {number:02}: invalid input at {error}"))?,
+    };
+
+    if let Some(cache) = cache {
+        for part in &report.parts {
+            if let Err(error) = cache.put(number, part.part, key, &part.answer) {
+                eprintln!("warning: {error}");
+            }
+        }
+    }
+
+    let cached_parts = cached.iter().map(|&(part, _)| part).collect::<Vec<_>>();
+    report
+        .parts
+        .extend(cached.into_iter().map(|(part, answer)| PartReport {
+            part,
+            answer,
+            elapsed: Duration::ZERO,
+        }));
+    report.parts.sort_by_key(|part| part.part);
+
+    Ok(Solved {
+        report,
+        cached: cached_parts,
+        timed_out,
+        explanations,
+    })
+}
+
+fn print_outcome(day: &Day, outcome: &Outcome) {
+    match outcome {
+        Outcome::Solved(solved) => print_report(day, solved),
+        Outcome::Failed(error) => {
+            println!("Day {:02}: {}", day.puzzle.day(), day.puzzle.title());
+            println!("  failed: {error}");
+        }
+        Outcome::Panicked(message) => {
+            println!("Day {:02}: {}", day.puzzle.day(), day.puzzle.title());
+            println!("  panicked: {message}");
+        }
+    }
+}
+
+fn print_report(day: &Day, solved: &Solved) {
+    let Solved {
+        report,
+        cached,
+        timed_out,
+        explanations,
+    } = solved;
+    println!("Day {:02}: {}", report.day, day.puzzle.title());
+
+    for part in &report.parts {
+        let answer = part.answer.to_string();
+        let timing = match cached.contains(&part.part) {
+            true => "cached".to_string(),
+            false => format!("{:.2?}", part.elapsed),
+        };
+
+        if answer.contains('\n') {
+            println!("  Part {}: ({timing})", part.part);
+            answer.lines().for_each(|line| println!("    {line}"));
+        } else {
+            println!("  Part {}: {answer} ({timing})", part.part);
+        }
+        for (_, explanation) in explanations.iter().filter(|(other, _)| *other == part.part) {
+            for line in explanation.to_string().lines() {
+                println!("    {line}");
+            }
+        }
+    }
+    for (part, error) in timed_out {
+        println!("  Part {part}: {error}");
+    }
+}
+
+/// The report as JSON, with `"cached": ["part1", ..]` listing the parts
+/// that came from the cache instead of their timings, `"timed_out":
+/// {"part2": 5.0, ..}` the budget in seconds of the parts that ran out and
+/// `"explanation": {"part1": {..}, ..}` the notes of the parts explained.
+fn report_to_json(solved: &Solved) -> Value {
+    let Solved {
+        report,
+        cached,
+        timed_out,
+        explanations,
+    } = solved;
+    let mut json = report.to_json();
+
+    if !cached.is_empty() {
+        if let Some(Value::Object(timings)) = json.get_mut("timings") {
+            for part in cached {
+                timings.remove(&format!("part{part}_ns"));
+            }
+            if cached.len() == report.parts.len() {
+                timings.remove("parse_ns");
+            }
+        }
+        json["cached"] = cached.iter().map(|part| format!("part{part}")).collect();
+    }
+    if !timed_out.is_empty() {
+        json["timed_out"] = timed_out
+            .iter()
+            .map(|(part, TimedOut(budget))| (format!("part{part}"), json!(budget.as_secs_f64())))
+            .collect();
+    }
+    if !explanations.is_empty() {
+        json["explanation"] = explanations
+            .iter()
+            .map(|(part, explanation)| (format!("part{part}"), explanation.to_json()))
+            .collect();
+    }
+
+    json
+}
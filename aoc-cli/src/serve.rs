@@ -0,0 +1,434 @@
+use crate::answers::Answers;
+use crate::days::{DAYS, WORKSPACE_DIR};
+use crate::history::{self, Record};
+use crate::ServeArgs;
+use aoc::{Answer, Part};
+use serde_json::{json, Value};
+use std::fmt::Write as _;
+use std::io::{Read, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::path::Path;
+use std::time::Duration;
+
+/// Timings of each part kept for its sparkline.
+const HISTORY: usize = 20;
+
+/// Serves the dashboard on localhost until interrupted.
+pub fn serve(args: &ServeArgs) -> Result<(), String> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, args.port))
+        .map_err(|error| format!("cannot listen on port {}: {error}", args.port))?;
+    let address = listener.local_addr().map_err(|error| error.to_string())?;
+
+    println!("Serving the dashboard on http://{address}/");
+    for stream in listener.incoming() {
+        if let Err(error) = stream.and_then(handle) {
+            eprintln!("warning: {error}");
+        }
+    }
+
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream) -> std::io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+
+    // Only the request line matters, so one read of the head is enough
+    let mut head = [0; 8192];
+    let read = stream.read(&mut head)?;
+    let head = String::from_utf8_lossy(&head[..read]);
+    let mut request = head.lines().next().unwrap_or_default().split(' ');
+    let (method, target) = (request.next().unwrap_or_default(), request.next());
+
+    let response = match Dashboard::load() {
+        Ok(dashboard) => route(method, target.unwrap_or("/"), &dashboard),
+        Err(error) => Response::new("500 Internal Server Error", "text/plain", error),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.content_type,
+        response.body.len(),
+        response.body
+    )
+}
+
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Response {
+    fn new(status: &'static str, content_type: &'static str, body: String) -> Self {
+        Self {
+            status,
+            content_type,
+            body,
+        }
+    }
+
+    fn ok(content_type: &'static str, body: String) -> Self {
+        Self::new("200 OK", content_type, body)
+    }
+}
+
+fn route(method: &str, target: &str, dashboard: &Dashboard) -> Response {
+    if method != "GET" {
+        return Response::new(
+            "405 Method Not Allowed",
+            "text/plain",
+            format!("{method} is not supported"),
+        );
+    }
+
+    let path = target.split('?').next().unwrap_or_default();
+    match path {
+        "/" => Response::ok("text/html", dashboard.to_html()),
+        "/api/days" => Response::ok("application/json", dashboard.to_json().to_string()),
+        "/crt.svg" => match dashboard.crt() {
+            Some(screen) => Response::ok("image/svg+xml", crt_svg(&screen)),
+            None => Response::new(
+                "404 Not Found",
+                "text/plain",
+                "day 10 part 2 has not been run yet".to_string(),
+            ),
+        },
+        _ => Response::new("404 Not Found", "text/plain", format!("no page at {path}")),
+    }
+}
+
+/// How the latest answer to a part compares with `answers.toml`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Status {
+    Passed,
+    Failed,
+    Unverified,
+    Unsolved,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Status::Passed => "passed",
+            Status::Failed => "failed",
+            Status::Unverified => "unverified",
+            Status::Unsolved => "unsolved",
+        }
+    }
+}
+
+struct PartStatus {
+    part: Part,
+    answer: Option<Answer>,
+    status: Status,
+    /// Seconds since the epoch and nanoseconds taken, oldest first.
+    history: Vec<(u64, u64)>,
+}
+
+struct DayStatus {
+    day: u8,
+    title: &'static str,
+    parts: Vec<PartStatus>,
+}
+
+struct Dashboard {
+    days: Vec<DayStatus>,
+}
+
+impl Dashboard {
+    /// Reads the history of `run` and the accepted answers afresh.
+    fn load() -> Result<Self, String> {
+        let answers = Answers::load(&Path::new(WORKSPACE_DIR).join("answers.toml"))?;
+        let records = history::load(&history::path())?;
+
+        Ok(Self::new(&records, &answers))
+    }
+
+    fn new(records: &[Record], answers: &Answers) -> Self {
+        let days = DAYS
+            .iter()
+            .map(|day| {
+                let number = day.puzzle.day();
+                let expected = answers.get(number, aoc::input::INPUT_FILE);
+                let parts = Part::ALL
+                    .iter()
+                    .map(|&part| {
+                        let records = records
+                            .iter()
+                            .filter(|record| record.day == number && record.part() == Some(part))
+                            .collect::<Vec<_>>();
+                        let answer = records
+                            .last()
+                            .and_then(|record| Answer::from_json(&record.answer));
+                        let status = match (&answer, expected.and_then(|e| e.get(part))) {
+                            (None, _) => Status::Unsolved,
+                            (Some(_), None) => Status::Unverified,
+                            (Some(answer), Some(expected)) if expected.matches(answer) => {
+                                Status::Passed
+                            }
+                            (Some(_), Some(_)) => Status::Failed,
+                        };
+
+                        PartStatus {
+                            part,
+                            answer,
+                            status,
+                            history: records[records.len().saturating_sub(HISTORY)..]
+                                .iter()
+                                .map(|record| (record.at, record.ns))
+                                .collect(),
+                        }
+                    })
+                    .collect();
+
+                DayStatus {
+                    day: number,
+                    title: day.puzzle.title(),
+                    parts,
+                }
+            })
+            .collect();
+
+        Self { days }
+    }
+
+    /// The latest screen drawn by day 10's CRT.
+    fn crt(&self) -> Option<String> {
+        let day = self.days.iter().find(|day| day.day == 10)?;
+
+        match &day.parts.iter().find(|part| part.part == Part::Two)?.answer {
+            Some(Answer::Text(screen)) => Some(screen.clone()),
+            _ => None,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let days = self
+            .days
+            .iter()
+            .map(|day| {
+                let parts = day
+                    .parts
+                    .iter()
+                    .map(|part| {
+                        json!({
+                            "part": part.part.to_string(),
+                            "answer": part.answer.as_ref().map(Answer::to_json),
+                            "status": part.status.name(),
+                            "history": part
+                                .history
+                                .iter()
+                                .map(|&(at, ns)| json!({ "at": at, "ns": ns }))
+                                .collect::<Vec<_>>(),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                json!({ "day": day.day, "title": day.title, "parts": parts })
+            })
+            .collect::<Vec<_>>();
+
+        json!({ "days": days })
+    }
+
+    fn to_html(&self) -> String {
+        let mut rows = String::new();
+
+        for day in &self.days {
+            for (i, part) in day.parts.iter().enumerate() {
+                let answer = match &part.answer {
+                    Some(answer) if answer.to_string().contains('\n') => "(screen)".to_string(),
+                    Some(answer) => escape(&answer.to_string()),
+                    None => "—".to_string(),
+                };
+                let latest = part.history.last().map_or("—".to_string(), |&(_, ns)| {
+                    format!("{:.2?}", Duration::from_nanos(ns))
+                });
+                let day = match i {
+                    0 => format!(
+                        "<td rowspan=\"{}\">{:02}</td><td rowspan=\"{0}\">{}</td>",
+                        day.parts.len(),
+                        day.day,
+                        escape(day.title)
+                    ),
+                    _ => String::new(),
+                };
+
+                let _ = writeln!(
+                    rows,
+                    "<tr>{day}<td>{}</td><td><code>{answer}</code></td><td class=\"{status}\">{status}</td><td>{latest}</td><td>{}</td></tr>",
+                    part.part,
+                    sparkline(&part.history),
+                    status = part.status.name(),
+                );
+            }
+        }
+
+        let crt = match self.crt() {
+            Some(_) => "<h2>Day 10 CRT</h2>\n<img src=\"/crt.svg\" alt=\"Day 10 CRT\">\n",
+            None => "",
+        };
+
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Advent of Code 2022</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; }}
+table {{ border-collapse: collapse; }}
+td, th {{ border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: left; }}
+.passed {{ color: #080; }}
+.failed {{ color: #c00; font-weight: bold; }}
+.unverified, .unsolved {{ color: #888; }}
+</style>
+</head>
+<body>
+<h1>Advent of Code 2022</h1>
+<p>Latest answers from <code>aoc run</code> on each day's input.txt, checked against answers.toml. Also as <a href="/api/days">JSON</a>.</p>
+<table>
+<tr><th>Day</th><th>Title</th><th>Part</th><th>Answer</th><th>Status</th><th>Latest</th><th>History</th></tr>
+{rows}</table>
+{crt}</body>
+</html>
+"#
+        )
+    }
+}
+
+/// A small line chart of the timings in `history`, scaled to the slowest.
+fn sparkline(history: &[(u64, u64)]) -> String {
+    const WIDTH: usize = 120;
+    const HEIGHT: usize = 24;
+
+    let slowest = history.iter().map(|&(_, ns)| ns).max().unwrap_or(0).max(1);
+    let step = WIDTH as f64 / history.len().saturating_sub(1).max(1) as f64;
+    let mut points = history
+        .iter()
+        .enumerate()
+        .map(|(i, &(_, ns))| {
+            let y = HEIGHT as f64 - 2.0 - (HEIGHT - 4) as f64 * ns as f64 / slowest as f64;
+            format!("{:.1},{y:.1}", i as f64 * step)
+        })
+        .collect::<Vec<_>>();
+
+    // A single timing becomes a dot thanks to the round caps
+    if let [point] = &points[..] {
+        points.push(point.clone());
+    }
+
+    format!(
+        r##"<svg width="{WIDTH}" height="{HEIGHT}"><polyline points="{}" fill="none" stroke="#36c" stroke-width="1.5" stroke-linecap="round"/></svg>"##,
+        points.join(" ")
+    )
+}
+
+/// The screen as an SVG image, one square per pixel.
+fn crt_svg(screen: &str) -> String {
+    const SCALE: usize = 10;
+
+    let width = screen.lines().map(str::len).max().unwrap_or(0);
+    let height = screen.lines().count();
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges">"#,
+        width * SCALE,
+        height * SCALE
+    );
+    svg.push_str(r##"<rect width="100%" height="100%" fill="#0f0f23"/>"##);
+
+    for (y, row) in screen.lines().enumerate() {
+        for (x, pixel) in row.bytes().enumerate() {
+            if pixel == b'#' {
+                let _ = write!(
+                    svg,
+                    r##"<rect x="{x}" y="{y}" width="1" height="1" fill="#ffff66"/>"##
+                );
+            }
+        }
+    }
+
+    svg + "</svg>"
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day-01."input.txt"]
+part1 = 24000
+part2 = 45000
+"#;
+
+    fn record(day: u8, part: u8, answer: Value, ns: u64) -> Record {
+        Record {
+            at: ns,
+            day,
+            part,
+            answer,
+            ns,
+        }
+    }
+
+    fn dashboard() -> Dashboard {
+        let answers = toml::from_str(ANSWERS).unwrap();
+        let records = [
+            record(1, 1, json!(1), 30),
+            record(1, 1, json!(24000), 20),
+            record(1, 2, json!(1), 10),
+            record(10, 2, json!(["#.", ".#"]), 10),
+        ];
+
+        Dashboard::new(&records, &answers)
+    }
+
+    #[test]
+    fn test_status() {
+        let json = route("GET", "/api/days", &dashboard()).body;
+        let days = serde_json::from_str::<Value>(&json).unwrap();
+        let status = |day: usize, part: usize| days["days"][day]["parts"][part]["status"].clone();
+
+        assert_eq!(json!(24000), days["days"][0]["parts"][0]["answer"]);
+        assert_eq!(json!("passed"), status(0, 0));
+        assert_eq!(json!("failed"), status(0, 1));
+        assert_eq!(json!("unsolved"), status(1, 0));
+        assert_eq!(json!("unverified"), status(9, 1));
+        assert_eq!(
+            2,
+            days["days"][0]["parts"][0]["history"]
+                .as_array()
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn test_routes() {
+        let dashboard = dashboard();
+
+        let page = route("GET", "/", &dashboard);
+        assert_eq!("200 OK", page.status);
+        assert!(page.body.contains("Calorie Counting"));
+        assert!(page.body.contains(r#"<img src="/crt.svg""#));
+
+        let crt = route("GET", "/crt.svg?refresh", &dashboard).body;
+        assert_eq!(2, crt.matches(r##"fill="#ffff66""##).count());
+        assert!(crt.contains(r#"viewBox="0 0 2 2""#));
+
+        assert_eq!("404 Not Found", route("GET", "/nope", &dashboard).status);
+        assert_eq!(
+            "405 Method Not Allowed",
+            route("POST", "/", &dashboard).status
+        );
+    }
+}
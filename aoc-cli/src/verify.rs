@@ -0,0 +1,152 @@
+use crate::answers::Answers;
+use crate::days::{self, Day, Run, RunOptions, WORKSPACE_DIR};
+use crate::VerifyArgs;
+use aoc::examples::{self, Expectations, EXAMPLES_DIR};
+use aoc::input::{normalize, INPUT_FILE};
+use aoc::params::{Config, Params};
+use aoc::{InputSource, Part};
+use std::path::Path;
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+/// An input to solve and the answers it should give.
+struct Case {
+    file: String,
+    input: Result<String, String>,
+    params: Params,
+    expected: Expectations,
+}
+
+/// Solves each selected day and compares the answers with `answers.toml`,
+/// or with the sidecars of its examples.
+pub fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let answers = Answers::load(&Path::new(WORKSPACE_DIR).join("answers.toml"))?;
+    let selected = days::select(args.day)?;
+    let config = days::config()?;
+    let mut options = RunOptions {
+        timeouts: config.timeouts.clone(),
+        ..RunOptions::default()
+    };
+    for timeout in &args.timeout {
+        options.timeouts.set(timeout)?;
+    }
+    let mut summary = Summary::default();
+
+    for day in selected {
+        let number = day.puzzle.day();
+        let cases = if args.test {
+            example_cases(day, &config)?
+        } else {
+            vec![Case {
+                file: INPUT_FILE.to_string(),
+                input: InputSource::Bundled
+                    .read(day.dir())
+                    .map_err(|error| error.to_string()),
+                params: day.params(&config, &InputSource::Bundled, &[])?,
+                expected: answers.get(number, INPUT_FILE).cloned().unwrap_or_default(),
+            }]
+        };
+
+        if cases.is_empty() {
+            summary.missing += Part::ALL.len();
+            println!("missing  Day {number:02}: no examples in {EXAMPLES_DIR}/");
+        }
+
+        for case in cases {
+            check(day, &case, &options, &mut summary);
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        summary.passed, summary.failed, summary.missing
+    );
+
+    match summary.failed {
+        0 => Ok(()),
+        failed => Err(format!("{failed} answer(s) changed")),
+    }
+}
+
+fn example_cases(day: &Day, config: &Config) -> Result<Vec<Case>, String> {
+    examples::discover(day.dir())?
+        .into_iter()
+        .map(|example| {
+            let source = InputSource::File(example.path.clone());
+
+            Ok(Case {
+                file: format!("{EXAMPLES_DIR}/{}.txt", example.name),
+                input: Ok(normalize(&example.raw)),
+                params: day.params(config, &source, &example.params)?,
+                expected: example.expected,
+            })
+        })
+        .collect()
+}
+
+fn check(day: &Day, case: &Case, options: &RunOptions, summary: &mut Summary) {
+    let number = day.puzzle.day();
+    let input = match &case.input {
+        Ok(input) => input,
+        Err(error) => {
+            summary.missing += Part::ALL.len();
+            println!("missing  Day {number:02}: {error}");
+            return;
+        }
+    };
+    let Run {
+        report, timed_out, ..
+    } = match day.run(input, &case.params, &Part::ALL, options) {
+        Ok(run) => run,
+        Err(error) => {
+            summary.failed += Part::ALL.len();
+            println!(
+                "FAIL     Day {number:02} ({}): invalid input at {error}",
+                case.file
+            );
+            return;
+        }
+    };
+
+    for part in &report.parts {
+        let label = format!("Day {number:02} part {} ({})", part.part, case.file);
+
+        match case.expected.get(part.part) {
+            Some(expected) if expected.matches(&part.answer) => {
+                summary.passed += 1;
+                println!("pass     {label}");
+            }
+            Some(expected) => {
+                summary.failed += 1;
+                println!("FAIL     {label}");
+                println!("  expected: {}", indent(&expected.to_string()));
+                println!("  got:      {}", indent(&part.answer.to_string()));
+            }
+            None => {
+                summary.missing += 1;
+                println!("missing  {label}: got {}", indent(&part.answer.to_string()));
+            }
+        }
+    }
+    for (part, error) in timed_out {
+        summary.failed += 1;
+        println!(
+            "FAIL     Day {number:02} part {part} ({}): {error}",
+            case.file
+        );
+    }
+}
+
+/// Puts multi-line answers on their own lines under the label.
+fn indent(answer: &str) -> String {
+    if answer.contains('\n') {
+        answer.lines().map(|line| format!("\n    {line}")).collect()
+    } else {
+        answer.to_string()
+    }
+}
@@ -0,0 +1,44 @@
+use crate::days;
+use crate::VisualizeArgs;
+use aoc::visual::{Player, Recorder};
+use std::fs::File;
+use std::io::BufWriter;
+
+/// Plays the simulation of the selected day in the terminal, or writes its
+/// frames to a file with `--headless`.
+pub fn visualize(args: &VisualizeArgs) -> Result<(), String> {
+    let day = days::find(args.day).ok_or(format!("day {} is not available", args.day))?;
+    let visualize = day.visualize.ok_or_else(|| {
+        let days = days::DAYS
+            .iter()
+            .filter(|day| day.visualize.is_some())
+            .map(|day| day.puzzle.day().to_string())
+            .collect::<Vec<_>>();
+        format!(
+            "day {} has nothing to visualize, try one of: {}",
+            args.day,
+            days.join(", ")
+        )
+    })?;
+
+    let input = args
+        .source()
+        .read(day.dir())
+        .map_err(|error| error.to_string())?;
+    let invalid = |error| format!("day {:02}: invalid input at {error}", args.day);
+
+    match &args.headless {
+        Some(path) => {
+            let file = File::create(path)
+                .map_err(|error| format!("cannot create {}: {error}", path.display()))?;
+            let mut recorder = Recorder::new(BufWriter::new(file), args.every);
+
+            visualize(&input, args.part, &mut recorder).map_err(invalid)?;
+            recorder
+                .into_inner()
+                .map(|_| ())
+                .map_err(|error| format!("cannot write {}: {error}", path.display()))
+        }
+        None => visualize(&input, args.part, &mut Player::new(args.fps)).map_err(invalid),
+    }
+}
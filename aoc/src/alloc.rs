@@ -0,0 +1,158 @@
+//! Allocation counting for `aoc profile`.
+//!
+//! A binary opts in by installing [`Counting`] as its global allocator.
+//! Counters are kept per thread and only move inside [`measure`], so the
+//! rest of the program pays a thread-local flag check per allocation.
+
+use crate::bench::Phase;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// What a phase allocated while it ran.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Allocations {
+    /// Calls to allocate or grow memory.
+    pub count: usize,
+    /// Bytes requested by those calls.
+    pub bytes: usize,
+    /// Most bytes held at once on top of what was live when it started.
+    pub peak: usize,
+}
+
+/// Allocations of each phase of one day.
+#[derive(Clone, Debug)]
+pub struct AllocReport {
+    pub day: u8,
+    pub phases: Vec<(Phase, Allocations)>,
+}
+
+#[derive(Clone, Copy)]
+struct Counters {
+    enabled: bool,
+    count: usize,
+    bytes: usize,
+    live: isize,
+    peak: isize,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            enabled: false,
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+fn record(allocated: usize, freed: usize) {
+    // `try_with` because allocations still happen while a thread shuts down
+    let _ = COUNTERS.try_with(|counters| {
+        let mut current = counters.get();
+
+        if current.enabled {
+            if allocated > 0 {
+                current.count += 1;
+                current.bytes += allocated;
+            }
+            current.live += allocated as isize - freed as isize;
+            current.peak = current.peak.max(current.live);
+            counters.set(current);
+        }
+    });
+}
+
+/// [`System`] allocator that counts what [`measure`] asks it to.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Runs `f`, counting what it allocates on this thread. Always reports
+/// zero unless [`Counting`] is the global allocator.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Allocations) {
+    COUNTERS.with(|counters| {
+        counters.set(Counters {
+            enabled: true,
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    });
+    let result = f();
+    let counters = COUNTERS.with(|counters| {
+        let mut current = counters.get();
+        current.enabled = false;
+        counters.set(current);
+        current
+    });
+
+    let allocations = Allocations {
+        count: counters.count,
+        bytes: counters.bytes,
+        peak: counters.peak.max(0) as usize,
+    };
+    (result, allocations)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        let (_, allocations) = measure(|| {
+            let mut numbers = Vec::<u64>::with_capacity(4);
+            numbers.extend([1, 2, 3, 4, 5]);
+            drop(vec![0u8; 1000]);
+            numbers
+        });
+
+        assert_eq!(3, allocations.count);
+        assert_eq!(32 + 64 + 1000, allocations.bytes);
+        assert_eq!(64 + 1000, allocations.peak);
+    }
+
+    #[test]
+    fn test_measure_nothing() {
+        let numbers = (1..=3).collect::<Vec<i32>>();
+        let (sum, allocations) = measure(|| numbers.iter().sum::<i32>());
+
+        assert_eq!(6, sum);
+        assert_eq!(Allocations::default(), allocations);
+    }
+}
@@ -0,0 +1,84 @@
+use serde::Deserialize;
+use std::fmt;
+
+/// Answer to one part of a puzzle.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(number: $ty) -> Self {
+                    i64::try_from(number)
+                        .map(Answer::Number)
+                        .unwrap_or_else(|_| Answer::Text(number.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u32, u64, usize, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// An accepted answer as written in a TOML file.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl Expected {
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match (self, answer) {
+            (Expected::Number(expected), Answer::Number(number)) => expected == number,
+            (expected, answer) => expected.to_string().trim_end() == answer.to_string().trim_end(),
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expected::Number(number) => write!(f, "{number}"),
+            Expected::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(Expected::Number(13140).matches(&Answer::Number(13140)));
+        assert!(!Expected::Number(13140).matches(&Answer::Number(13141)));
+        assert!(Expected::Text("##..\n..##\n".to_string()).matches(&Answer::from("##..\n..##")));
+    }
+}
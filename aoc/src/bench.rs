@@ -0,0 +1,120 @@
+use crate::Part;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// A timed step of solving a puzzle.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [
+        Phase::Parse,
+        Phase::Solve(Part::One),
+        Phase::Solve(Part::Two),
+    ];
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Solve(part) => f.pad(&format!("part{part}")),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            _ => s
+                .strip_prefix("part")
+                .ok_or(format!("invalid phase `{s}`"))?
+                .parse()
+                .map(Phase::Solve),
+        }
+    }
+}
+
+/// How many times each phase is run.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchOptions {
+    /// Untimed runs before sampling starts.
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+/// Sorted wall-clock timings of repeated runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Samples(Vec<Duration>);
+
+impl Samples {
+    pub fn new(mut timings: Vec<Duration>) -> Self {
+        assert!(!timings.is_empty(), "no samples");
+        timings.sort();
+
+        Self(timings)
+    }
+
+    /// Runs `f` `warmup` times, then times it `samples` times.
+    pub fn collect(options: &BenchOptions, mut f: impl FnMut()) -> Self {
+        (0..options.warmup).for_each(|_| f());
+
+        Self::new(
+            (0..options.samples.max(1))
+                .map(|_| {
+                    let start = Instant::now();
+                    f();
+                    start.elapsed()
+                })
+                .collect(),
+        )
+    }
+
+    pub fn min(&self) -> Duration {
+        self.0[0]
+    }
+
+    pub fn median(&self) -> Duration {
+        self.0[self.0.len() / 2]
+    }
+
+    pub fn max(&self) -> Duration {
+        self.0[self.0.len() - 1]
+    }
+}
+
+/// Timings of every phase of one day.
+#[derive(Clone, Debug)]
+pub struct BenchReport {
+    pub day: u8,
+    pub phases: Vec<(Phase, Samples)>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_phase_round_trip() {
+        for phase in Phase::ALL {
+            assert_eq!(Ok(phase), phase.to_string().parse());
+        }
+        assert!("part3".parse::<Phase>().is_err());
+    }
+
+    #[test]
+    fn test_samples() {
+        let samples = Samples::new([3, 1, 2].map(Duration::from_millis).to_vec());
+
+        assert_eq!(Duration::from_millis(1), samples.min());
+        assert_eq!(Duration::from_millis(2), samples.median());
+        assert_eq!(Duration::from_millis(3), samples.max());
+    }
+}
@@ -0,0 +1,236 @@
+//! Time budgets for solvers that may never finish on a bad input.
+//!
+//! Loops that only end once the input cooperates call [`check`], which
+//! unwinds out of the solver once the budget given to [`with_timeout`] is
+//! spent.
+//!
+//! Budgets are set per day and part by [`Timeouts`], read from the
+//! `[timeouts]` table of `aoc.toml`:
+//!
+//! ```toml
+//! [timeouts]
+//! all = 60      # seconds for every part of every day
+//! 14 = 10       # every part of day 14
+//! "15.2" = 30   # part 2 of day 15
+//! ```
+
+use crate::Part;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+thread_local! {
+    /// Set by the watchdog once the budget of this thread's solver is spent.
+    static CANCELLED: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// Payload of the unwinding started by [`check`].
+struct Cancelled;
+
+/// A solver that was still running when its budget was spent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimedOut(pub Duration);
+
+impl fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "timed out after {}s", self.0.as_secs_f64())
+    }
+}
+
+/// Stops the solver if its budget is spent. Call it from every loop that
+/// relies on the input to ever end.
+#[inline]
+pub fn check() {
+    let cancelled = CANCELLED.with(|flag| {
+        flag.borrow()
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    });
+
+    if cancelled {
+        // Unlike a panic, this skips the panic hook and prints nothing
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// Runs `solve` on this thread, stopping it at the next [`check`] once
+/// `budget` is spent. Without a budget, `solve` runs for as long as it
+/// takes.
+pub fn with_timeout<R>(budget: Option<Duration>, solve: impl FnOnce() -> R) -> Result<R, TimedOut> {
+    let Some(budget) = budget else {
+        return Ok(solve());
+    };
+    let flag = Arc::new(AtomicBool::new(false));
+    let (done, finished) = mpsc::channel::<()>();
+
+    let watchdog = thread::spawn({
+        let flag = flag.clone();
+        move || {
+            if finished.recv_timeout(budget) == Err(RecvTimeoutError::Timeout) {
+                flag.store(true, Ordering::Relaxed);
+            }
+        }
+    });
+
+    let (result, _) = crate::scoped::with(&CANCELLED, flag, || {
+        panic::catch_unwind(AssertUnwindSafe(solve))
+    });
+    drop(done);
+    let _ = watchdog.join();
+
+    match result {
+        Ok(answer) => Ok(answer),
+        Err(payload) if payload.is::<Cancelled>() => Err(TimedOut(budget)),
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Which parts a budget applies to, from the least to the most specific.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Scope {
+    All,
+    Day(u8),
+    Part(u8, Part),
+}
+
+impl FromStr for Scope {
+    type Err = String;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        let scope = match key.split_once('.') {
+            _ if key == "all" => Some(Scope::All),
+            Some((day, part)) => day
+                .parse()
+                .ok()
+                .zip(part.parse().ok())
+                .map(|(day, part)| Scope::Part(day, part)),
+            None => key.parse().ok().map(Scope::Day),
+        };
+
+        scope.ok_or(format!(
+            "invalid timeout `{key}`, expected `all`, a day such as `14` or a part such as `15.2`"
+        ))
+    }
+}
+
+/// Time each part may take, keyed by `all`, a day such as `14`, or a day
+/// and part such as `15.2`. The most specific key wins.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(try_from = "BTreeMap<String, f64>")]
+pub struct Timeouts(BTreeMap<Scope, Duration>);
+
+impl TryFrom<BTreeMap<String, f64>> for Timeouts {
+    type Error = String;
+
+    fn try_from(budgets: BTreeMap<String, f64>) -> Result<Self, Self::Error> {
+        let mut timeouts = Self::default();
+        for (key, seconds) in budgets {
+            timeouts.insert(&key, seconds)?;
+        }
+        Ok(timeouts)
+    }
+}
+
+impl Timeouts {
+    /// Reads a `KEY=SECONDS` argument, or just `SECONDS` for every part,
+    /// and sets it over the current budgets.
+    pub fn set(&mut self, arg: &str) -> Result<(), String> {
+        let (key, seconds) = arg.rsplit_once('=').unwrap_or(("all", arg));
+        let seconds = seconds
+            .parse()
+            .map_err(|_| format!("invalid timeout `{seconds}`, expected seconds"))?;
+
+        self.insert(key, seconds)
+    }
+
+    fn insert(&mut self, key: &str, seconds: f64) -> Result<(), String> {
+        let scope = key.parse()?;
+        let budget = Some(seconds)
+            .filter(|&seconds| seconds > 0.0)
+            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            .ok_or(format!(
+                "invalid timeout {seconds} for `{key}`, expected seconds above 0"
+            ))?;
+
+        self.0.insert(scope, budget);
+        Ok(())
+    }
+
+    /// Budget of `part` of `day`, if any.
+    pub fn get(&self, day: u8, part: Part) -> Option<Duration> {
+        [Scope::Part(day, part), Scope::Day(day), Scope::All]
+            .iter()
+            .find_map(|scope| self.0.get(scope))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_with_timeout() {
+        let budget = Duration::from_millis(20);
+        let spin = || loop {
+            check();
+            thread::yield_now();
+        };
+
+        assert_eq!(Err(TimedOut(budget)), with_timeout(Some(budget), spin));
+        assert_eq!(Ok(7), with_timeout(Some(budget), || 7));
+        assert_eq!(Ok(7), with_timeout(None, || 7));
+        // The budget is over, but it no longer applies
+        check();
+    }
+
+    #[test]
+    fn test_other_panics() {
+        let result = panic::catch_unwind(|| {
+            with_timeout(Some(Duration::from_secs(60)), || panic!("not a timeout"))
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_timeouts() {
+        let mut timeouts: Timeouts = toml::from_str("all = 60\n14 = 10\n").unwrap();
+        timeouts.set("15.2=0.5").unwrap();
+
+        assert_eq!(Some(Duration::from_secs(10)), timeouts.get(14, Part::Two));
+        assert_eq!(
+            Some(Duration::from_millis(500)),
+            timeouts.get(15, Part::Two)
+        );
+        assert_eq!(Some(Duration::from_secs(60)), timeouts.get(15, Part::One));
+        assert_eq!(None, Timeouts::default().get(15, Part::One));
+
+        timeouts.set("5").unwrap();
+        assert_eq!(Some(Duration::from_secs(5)), timeouts.get(1, Part::One));
+        assert!(timeouts.set("15.3=1").is_err());
+        assert!(timeouts.set("day-15=1").is_err());
+        assert!(timeouts.set("15=0").is_err());
+        assert!(timeouts.set("15=inf").is_err());
+
+        // Days are numbers, however they are written
+        timeouts.set("09=2").unwrap();
+        assert_eq!(Some(Duration::from_secs(2)), timeouts.get(9, Part::Two));
+        let padded: Timeouts = toml::from_str("\"09.1\" = 3\n").unwrap();
+        assert_eq!(Some(Duration::from_secs(3)), padded.get(9, Part::One));
+        assert!(toml::from_str::<Timeouts>("all = -1\n").is_err());
+        assert!(toml::from_str::<Timeouts>("day-9 = 1\n").is_err());
+        assert_eq!(
+            "timed out after 0.5s",
+            TimedOut(Duration::from_millis(500)).to_string()
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Snapshots of long simulations, to pick them up where they stopped.
+//!
+//! A simulation calls [`resume`] once for the state to start from, then
+//! [`save`] between its steps. Under [`with_checkpoint`], the state goes to
+//! a file at most once per interval and is read back on the next run.
+
+use crate::{Part, Solution};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::cell::RefCell;
+use std::fs;
+use std::io::ErrorKind;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Where a solver keeps its snapshot, and what to do with it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checkpoint {
+    pub path: PathBuf,
+    /// What the snapshot belongs to, such as the day, the part and a hash of
+    /// the input. Snapshots of anything else are ignored.
+    pub key: String,
+    /// Time between two snapshots, or `None` to never write one.
+    pub every: Option<Duration>,
+    /// Whether to start from the snapshot left by an earlier run.
+    pub resume: bool,
+}
+
+/// A snapshot as written to its file.
+#[derive(Deserialize, Serialize)]
+struct Snapshot<T> {
+    key: String,
+    state: T,
+}
+
+/// The checkpoint of the solver running on this thread.
+struct Active {
+    checkpoint: Checkpoint,
+    last: Instant,
+    saved: usize,
+    /// Calls to [`save`], whether they wrote a snapshot or not.
+    calls: usize,
+    /// Writes a snapshot on this call to [`save`], then stops the solver as
+    /// a crash would.
+    stop_after: Option<usize>,
+    error: Option<String>,
+}
+
+/// Payload of the unwinding started by [`save`] once `stop_after` is reached.
+struct Stopped;
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Active>> = const { RefCell::new(None) };
+}
+
+/// Runs `solve` on this thread with `checkpoint`, then removes its
+/// snapshot, which a finished solver no longer needs. Also returns the
+/// first snapshot that could not be read or written, since a solver is
+/// better off running from the start than failing.
+pub fn with_checkpoint<R>(
+    checkpoint: Option<Checkpoint>,
+    solve: impl FnOnce() -> R,
+) -> (R, Option<String>) {
+    run(checkpoint, None, solve)
+}
+
+fn run<R>(
+    checkpoint: Option<Checkpoint>,
+    stop_after: Option<usize>,
+    solve: impl FnOnce() -> R,
+) -> (R, Option<String>) {
+    let Some(checkpoint) = checkpoint else {
+        return (solve(), None);
+    };
+    let path = checkpoint.path.clone();
+    let active = Active {
+        checkpoint,
+        last: Instant::now(),
+        saved: 0,
+        calls: 0,
+        stop_after,
+        error: None,
+    };
+
+    let (answer, active) = crate::scoped::with(&ACTIVE, active, solve);
+
+    let mut error = active.and_then(|active| active.error);
+    if let Err(remove) = fs::remove_file(&path) {
+        if remove.kind() != ErrorKind::NotFound {
+            error.get_or_insert(format!("cannot remove {}: {remove}", path.display()));
+        }
+    }
+
+    (answer, error)
+}
+
+/// The state saved by an earlier run of this solver, if it is to resume
+/// and there is one. Only the first call of a run gets it.
+pub fn resume<T: DeserializeOwned>() -> Option<T> {
+    ACTIVE.with_borrow_mut(|active| {
+        let active = active.as_mut()?;
+        if !std::mem::take(&mut active.checkpoint.resume) {
+            return None;
+        }
+
+        let path = &active.checkpoint.path;
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => return None,
+            Err(error) => {
+                active.error = Some(format!("cannot read {}: {error}", path.display()));
+                return None;
+            }
+        };
+        let snapshot = serde_json::from_str::<Snapshot<Value>>(&content)
+            .ok()
+            .filter(|snapshot| snapshot.key == active.checkpoint.key)?;
+
+        match serde_json::from_value(snapshot.state) {
+            Ok(state) => Some(state),
+            Err(error) => {
+                active.error = Some(format!("cannot resume from {}: {error}", path.display()));
+                None
+            }
+        }
+    })
+}
+
+/// Writes the state returned by `state` as the snapshot of this solver,
+/// unless the last one is more recent than the interval.
+pub fn save<T: Serialize>(state: impl FnOnce() -> T) {
+    let stop = ACTIVE.with_borrow_mut(|active| {
+        let Some(active) = active.as_mut() else {
+            return false;
+        };
+        active.calls += 1;
+        let stop = active.stop_after == Some(active.calls);
+        let due = active
+            .checkpoint
+            .every
+            .is_some_and(|every| active.saved == 0 || active.last.elapsed() >= every);
+        if !due && !stop {
+            return false;
+        }
+
+        let snapshot = Snapshot {
+            key: active.checkpoint.key.clone(),
+            state: state(),
+        };
+        let written = serde_json::to_string(&snapshot)
+            .map_err(|error| error.to_string())
+            .and_then(|content| crate::files::write_atomic(&active.checkpoint.path, &content));
+        if let Err(error) = written {
+            active.error.get_or_insert(error);
+        }
+        active.last = Instant::now();
+        active.saved += 1;
+
+        stop
+    });
+
+    if stop {
+        panic::resume_unwind(Box::new(Stopped));
+    }
+}
+
+/// Solves both parts of `input`, stops the solver halfway through with a
+/// single snapshot, as a crash would, and checks that resuming from it
+/// gives the same answer as an uninterrupted run.
+pub fn check<S: Solution>(input: &str) {
+    let parsed = S::parse(input).unwrap_or_else(|error| panic!("invalid input at {error}"));
+
+    for part in Part::ALL {
+        let checkpoint = |resume| Checkpoint {
+            path: std::env::temp_dir().join(format!(
+                "aoc-checkpoint-{}-{:02}-part{part}.json",
+                std::process::id(),
+                S::DAY
+            )),
+            key: format!("day {:02} part {part}", S::DAY),
+            every: None,
+            resume,
+        };
+        let solve = || S::solve(&parsed, part);
+
+        let (expected, _, calls) = count_saves(&checkpoint(false), solve);
+        assert!(calls > 0, "part {part} never saved a snapshot");
+
+        let stopped = panic::catch_unwind(AssertUnwindSafe(|| {
+            run(Some(checkpoint(false)), Some(calls.div_ceil(2)), solve)
+        }));
+        match stopped {
+            Err(payload) if payload.is::<Stopped>() => {}
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(_) => panic!("part {part} finished before its snapshot"),
+        }
+        assert!(
+            checkpoint(true).path.exists(),
+            "part {part} left no snapshot"
+        );
+
+        let (resumed, error) = run(Some(checkpoint(true)), None, solve);
+        assert_eq!(None, error, "part {part}");
+        assert_eq!(expected, resumed, "part {part} resumed to another answer");
+        assert!(
+            !checkpoint(true).path.exists(),
+            "part {part} kept its snapshot"
+        );
+    }
+}
+
+/// The answer of a whole run of `solve`, the number of snapshots it wrote
+/// and the number of times it called [`save`].
+fn count_saves<R>(checkpoint: &Checkpoint, solve: impl FnOnce() -> R) -> (R, usize, usize) {
+    let ((answer, counts), error) = run(Some(checkpoint.clone()), None, || {
+        let answer = solve();
+        let counts = ACTIVE.with_borrow(|active| {
+            active
+                .as_ref()
+                .map_or((0, 0), |active| (active.saved, active.calls))
+        });
+        (answer, counts)
+    });
+    assert_eq!(None, error);
+
+    (answer, counts.0, counts.1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn checkpoint(name: &str, resume: bool) -> Checkpoint {
+        Checkpoint {
+            path: std::env::temp_dir()
+                .join(format!("aoc-checkpoint-test-{}", std::process::id()))
+                .join(format!("{name}.json")),
+            key: "count".to_string(),
+            every: Some(Duration::ZERO),
+            resume,
+        }
+    }
+
+    /// Counts to 10 from where the snapshot left off, stopping after `stop`.
+    fn count(stop: u32) -> u32 {
+        let start = resume::<u32>().unwrap_or(0);
+
+        for n in start + 1..=10 {
+            save(|| n);
+            if n == stop {
+                panic::resume_unwind(Box::new(Stopped));
+            }
+        }
+        start
+    }
+
+    #[test]
+    fn test_resume() {
+        let stop = || {
+            let stopped = panic::catch_unwind(|| {
+                with_checkpoint(Some(checkpoint("resume", false)), || count(4))
+            });
+            assert!(stopped.is_err());
+            assert!(checkpoint("resume", false).path.exists());
+        };
+
+        stop();
+        assert_eq!(
+            (4, None),
+            with_checkpoint(Some(checkpoint("resume", true)), || count(0))
+        );
+        assert!(!checkpoint("resume", true).path.exists());
+
+        stop();
+        let mut other = checkpoint("resume", true);
+        other.key = "other".to_string();
+        assert_eq!((0, None), with_checkpoint(Some(other), || count(0)));
+        assert_eq!((0, None), with_checkpoint(None, || count(0)));
+    }
+
+    #[test]
+    fn test_interval() {
+        let mut hourly = checkpoint("interval", false);
+        hourly.every = Some(Duration::from_secs(3600));
+
+        assert_eq!((0, 1, 10), count_saves(&hourly, || count(0)));
+        hourly.every = None;
+        assert_eq!((0, 0, 10), count_saves(&hourly, || count(0)));
+    }
+
+    #[test]
+    fn test_unreadable() {
+        let path = checkpoint("unreadable", true).path;
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, r#"{"key":"count","state":"four"}"#).unwrap();
+
+        let (start, error) = with_checkpoint(Some(checkpoint("unreadable", true)), || count(0));
+        assert_eq!(0, start);
+        assert!(error.unwrap().starts_with("cannot resume from"));
+    }
+}
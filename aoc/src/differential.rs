@@ -0,0 +1,218 @@
+//! Differential testing of a solution against a deliberately naive one.
+//!
+//! A day that ships a reference solver generates many small inputs, solves
+//! each both ways and compares. When they disagree, the input is shrunk by
+//! dropping lines for as long as the two still disagree, so the case that
+//! gets printed is small enough to debug by hand.
+
+use crate::rng::Rng;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// How many inputs to generate, and from which seed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Options {
+    pub cases: usize,
+    pub seed: u64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            cases: 200,
+            seed: 0,
+        }
+    }
+}
+
+/// What a solver answered, or the message it panicked with.
+pub type Outcome<T> = Result<T, String>;
+
+/// An input the two solvers disagree on, shrunk as far as it would go.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch<T> {
+    /// Which generated input disagreed first, starting at 0.
+    pub case: usize,
+    pub seed: u64,
+    /// Lines of the generated input, before shrinking.
+    pub original_lines: usize,
+    pub input: String,
+    pub optimized: Outcome<T>,
+    pub reference: Outcome<T>,
+}
+
+impl<T: fmt::Debug> fmt::Display for Mismatch<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "case {} of seed {} disagrees, shrunk from {} to {} lines:",
+            self.case,
+            self.seed,
+            self.original_lines,
+            self.input.lines().count()
+        )?;
+        for line in self.input.lines() {
+            writeln!(f, "    {line}")?;
+        }
+        writeln!(f, "optimized: {:?}", self.optimized)?;
+        write!(f, "reference: {:?}", self.reference)
+    }
+}
+
+fn outcome<T>(solve: impl Fn(&str) -> T, input: &str) -> Outcome<T> {
+    panic::catch_unwind(AssertUnwindSafe(|| solve(input))).map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked".to_string())
+    })
+}
+
+/// Solves `options.cases` inputs from `generate` with both solvers and
+/// returns the first disagreement, shrunk.
+///
+/// A solver that panics counts as answering with its panic message, so a
+/// panic on one side only is a disagreement too.
+pub fn compare<T: PartialEq>(
+    options: Options,
+    mut generate: impl FnMut(&mut Rng) -> String,
+    optimized: impl Fn(&str) -> T,
+    reference: impl Fn(&str) -> T,
+) -> Result<(), Mismatch<T>> {
+    let mut rng = Rng::new(options.seed);
+    let disagree = |input: &str| {
+        let answers = (outcome(&optimized, input), outcome(&reference, input));
+        (answers.0 != answers.1).then_some(answers)
+    };
+
+    for case in 0..options.cases {
+        let input = generate(&mut rng);
+
+        if disagree(&input).is_some() {
+            let shrunk = shrink(&input, |candidate| disagree(candidate).is_some());
+            let (optimized, reference) = disagree(&shrunk).expect("shrinking kept a mismatch");
+
+            return Err(Mismatch {
+                case,
+                seed: options.seed,
+                original_lines: input.lines().count(),
+                input: shrunk,
+                optimized,
+                reference,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes runs of lines from `input`, halving their length down to single
+/// lines, for as long as `fails` still holds. Never shrinks to nothing.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut lines = input.lines().collect::<Vec<_>>();
+    let mut chunk = lines.len() / 2;
+
+    while chunk > 0 {
+        let mut start = 0;
+        let mut removed = false;
+
+        while start + chunk <= lines.len() {
+            let candidate = [&lines[..start], &lines[start + chunk..]].concat();
+
+            if !candidate.is_empty() && fails(&to_input(&candidate)) {
+                lines = candidate;
+                removed = true;
+            } else {
+                start += chunk;
+            }
+        }
+
+        if !removed {
+            chunk /= 2;
+        }
+    }
+
+    to_input(&lines)
+}
+
+fn to_input(lines: &[&str]) -> String {
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Lines of numbers below 100, one per line.
+    fn numbers(rng: &mut Rng) -> String {
+        (0..rng.range(1..=20))
+            .map(|_| format!("{}\n", rng.below(100)))
+            .collect()
+    }
+
+    fn sum(input: &str) -> usize {
+        input
+            .lines()
+            .map(|line| line.parse::<usize>().unwrap())
+            .sum()
+    }
+
+    #[test]
+    fn test_agree() {
+        assert_eq!(
+            Ok(()),
+            compare(Options::default(), numbers, sum, |input| {
+                input
+                    .lines()
+                    .fold(0, |total, line| total + line.parse::<usize>().unwrap())
+            })
+        );
+    }
+
+    #[test]
+    fn test_shrinks_mismatch() {
+        // Wrong as soon as any line is over 42
+        let capped = |input: &str| {
+            input
+                .lines()
+                .map(|line| line.parse::<usize>().unwrap().min(42))
+                .sum::<usize>()
+        };
+        let mismatch = compare(Options::default(), numbers, capped, sum).unwrap_err();
+
+        assert_eq!(1, mismatch.input.lines().count());
+        assert!(mismatch.input.trim().parse::<usize>().unwrap() > 42);
+        assert!(mismatch.original_lines >= 1);
+        assert!(mismatch.to_string().contains("optimized: Ok(42)"));
+    }
+
+    #[test]
+    fn test_panic_is_a_mismatch() {
+        let mismatch = compare(
+            Options::default(),
+            numbers,
+            |input: &str| {
+                assert!(!input.contains("7\n"), "no sevens");
+                sum(input)
+            },
+            sum,
+        )
+        .unwrap_err();
+
+        assert_eq!(Err("no sevens".to_string()), mismatch.optimized);
+        assert_eq!(1, mismatch.input.lines().count());
+    }
+
+    #[test]
+    fn test_shrink() {
+        let input = "a\nb\nc\nd\ne\nf\ng\n";
+
+        assert_eq!("e\n", shrink(input, |input| input.contains('e')));
+        assert_eq!(
+            "b\nf\n",
+            shrink(input, |input| input.contains('b') && input.contains('f'))
+        );
+        assert_eq!(input, shrink(input, |input| input.lines().count() == 7));
+    }
+}
@@ -0,0 +1,85 @@
+use std::error::Error;
+use std::fmt;
+
+/// Malformed puzzle input, located by line and column.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column of the offending text within the line, starting at 1.
+    pub column: usize,
+    /// The whole offending line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error at `column` (starting at 1) of the line at `index` (starting at 0).
+    pub fn at(index: usize, column: usize, text: &str, message: impl Into<String>) -> Self {
+        Self {
+            line: index + 1,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error pointing at `token`, which must be a slice of `text`.
+    pub fn at_token(index: usize, text: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(text.as_ptr() as usize)
+            .filter(|&offset| offset <= text.len())
+            .unwrap_or(0);
+
+        Self::at(index, offset + 1, text, message)
+    }
+
+    /// Moves the error down by `lines`, for errors raised on a slice of the
+    /// input that does not start on its first line.
+    pub fn shift(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^", column = self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_at_token() {
+        let line = "move 3 from x to 2";
+        let error = ParseError::at_token(4, line, &line[12..13], "invalid stack `x`");
+
+        assert_eq!(5, error.line);
+        assert_eq!(13, error.column);
+        assert_eq!(6, error.shift(1).line);
+    }
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::at(9, 3, "R x", "invalid steps `x`");
+
+        assert_eq!(
+            "line 10, column 3: invalid steps `x`\n   |\n10 | R x\n   |   ^",
+            error.to_string()
+        );
+    }
+}
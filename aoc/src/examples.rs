@@ -0,0 +1,292 @@
+//! Example inputs with known answers.
+//!
+//! Each day keeps its examples in `examples/`, as `NAME.txt` holding the
+//! input and `NAME.toml` beside it holding the expected `part1` and `part2`.
+//! Either answer may be left out. Adding a file pair adds a test case.
+//!
+//! Examples that ask about other values than the real puzzle set them in a
+//! `[params]` table of the sidecar, see [`params`](crate::params).
+
+use crate::input::normalize;
+use crate::params::Params;
+use crate::{Expected, Part, Solution};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory of the examples, relative to the day's manifest.
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Expected answers of one input.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Expectations {
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+}
+
+impl Expectations {
+    pub fn get(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// Contents of the `NAME.toml` beside an example.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Sidecar {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+    #[serde(default)]
+    params: BTreeMap<String, i64>,
+}
+
+impl Sidecar {
+    fn read(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| format!("cannot read `{}`: {error}", path.display()))?;
+
+        toml::from_str(&content)
+            .map_err(|error| format!("cannot parse `{}`: {error}", path.display()))
+    }
+}
+
+/// An example input and the answers from its sidecar.
+#[derive(Clone, Debug)]
+pub struct Example {
+    /// File name without the `.txt` extension.
+    pub name: String,
+    pub path: PathBuf,
+    /// The input exactly as stored, before normalization.
+    pub raw: String,
+    pub expected: Expectations,
+    /// Parameters to solve it with, over the defaults of the real puzzle.
+    pub params: Vec<(String, i64)>,
+}
+
+/// Every example in the `examples/` directory of `day_dir`, sorted by name.
+/// A day without that directory has no examples.
+pub fn discover(day_dir: &Path) -> Result<Vec<Example>, String> {
+    let dir = day_dir.join(EXAMPLES_DIR);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(error) => return Err(format!("cannot read `{}`: {error}", dir.display())),
+    };
+    let mut examples = vec![];
+
+    for entry in entries {
+        let path = entry
+            .map_err(|error| format!("cannot read `{}`: {error}", dir.display()))?
+            .path();
+
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+
+        let sidecar = Sidecar::read(&path.with_extension("toml"))?;
+        let raw = fs::read_to_string(&path)
+            .map_err(|error| format!("cannot read `{}`: {error}", path.display()))?;
+
+        examples.push(Example {
+            name: path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned(),
+            raw,
+            path,
+            expected: Expectations {
+                part1: sidecar.part1,
+                part2: sidecar.part2,
+            },
+            params: sidecar.params.into_iter().collect(),
+        });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(examples)
+}
+
+/// Parameters set by the sidecar of the example at `path`.
+pub fn sidecar_params(path: &Path) -> Result<Vec<(String, i64)>, String> {
+    Ok(Sidecar::read(&path.with_extension("toml"))?
+        .params
+        .into_iter()
+        .collect())
+}
+
+/// Solves every example of `S` found in `day_dir`, once as stored and once
+/// with CRLF line endings, and panics listing each wrong answer.
+pub fn check<S: Solution>(day_dir: &str) {
+    let examples = discover(Path::new(day_dir)).unwrap_or_else(|error| panic!("{error}"));
+    let mut failures = vec![];
+
+    assert!(
+        !examples.is_empty(),
+        "no examples in {day_dir}/{EXAMPLES_DIR}"
+    );
+
+    for example in &examples {
+        let overrides = example
+            .params
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+            .collect::<Vec<_>>();
+        let params = match Params::new(S::PARAMS, &overrides) {
+            Ok(params) => params,
+            Err(error) => {
+                failures.push(format!("{}: {error}", example.name));
+                continue;
+            }
+        };
+        let variants = [
+            ("", example.raw.clone()),
+            (" with CRLF", example.raw.replace('\n', "\r\n")),
+        ];
+
+        for (variant, raw) in variants {
+            let input = match S::parse_with(&normalize(&raw), &params) {
+                Ok(input) => input,
+                Err(error) => {
+                    failures.push(format!(
+                        "{}{variant}: invalid input at {error}",
+                        example.name
+                    ));
+                    continue;
+                }
+            };
+
+            for part in Part::ALL {
+                let Some(expected) = example.expected.get(part) else {
+                    continue;
+                };
+                let answer = S::solve(&input, part);
+
+                if !expected.matches(&answer) {
+                    failures.push(format!(
+                        "{} part {part}{variant}: expected {expected}, got {answer}",
+                        example.name
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} example answer(s) wrong:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ParseError;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Lines";
+
+        type Input = Vec<String>;
+        type Part1 = usize;
+        type Part2 = String;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(str::to_string).collect())
+        }
+
+        fn part1(lines: &Self::Input) -> usize {
+            lines.len()
+        }
+
+        fn part2(lines: &Self::Input) -> String {
+            lines.join("\n")
+        }
+    }
+
+    /// A fresh day directory named after `test`, holding `files` in `examples/`.
+    fn day_dir(test: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{test}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join(EXAMPLES_DIR)).unwrap();
+
+        for (name, content) in files {
+            fs::write(dir.join(EXAMPLES_DIR).join(name), content).unwrap();
+        }
+
+        dir
+    }
+
+    #[test]
+    fn test_discover_and_check() {
+        let dir = day_dir(
+            "discover",
+            &[
+                ("b.txt", "x\ny\n"),
+                ("b.toml", "part1 = 2\npart2 = \"\"\"\nx\ny\n\"\"\"\n"),
+                ("a.txt", "x\n"),
+                ("a.toml", "part2 = \"x\""),
+                ("notes.md", "ignored"),
+            ],
+        );
+        let examples = discover(&dir).unwrap();
+
+        assert_eq!(
+            vec!["a", "b"],
+            examples.iter().map(|e| &e.name).collect::<Vec<_>>()
+        );
+        assert_eq!(None, examples[0].expected.get(Part::One));
+        check::<Lines>(dir.to_str().unwrap());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_reports_wrong_answers() {
+        let dir = day_dir("wrong", &[("a.txt", "x\n"), ("a.toml", "part1 = 2")]);
+        let result = std::panic::catch_unwind(|| check::<Lines>(dir.to_str().unwrap()));
+        fs::remove_dir_all(&dir).unwrap();
+
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("a part 1: expected 2, got 1"));
+        assert!(message.contains("a part 1 with CRLF: expected 2, got 1"));
+    }
+
+    #[test]
+    fn test_sidecar_params() {
+        let dir = day_dir(
+            "params",
+            &[
+                ("a.txt", "x\n"),
+                ("a.toml", "part1 = 1\n[params]\nlines = 2\n"),
+            ],
+        );
+        let example = &discover(&dir).unwrap()[0];
+        let params = sidecar_params(&example.path);
+        let result = std::panic::catch_unwind(|| check::<Lines>(dir.to_str().unwrap()));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(vec![("lines".to_string(), 2)], example.params);
+        assert_eq!(Ok(example.params.clone()), params);
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("a: unknown parameter `lines`, there are none"));
+    }
+
+    #[test]
+    fn test_missing_sidecar() {
+        let dir = day_dir("sidecar", &[("a.txt", "x\n")]);
+        let error = discover(&dir).unwrap_err();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(error.contains("a.toml"));
+    }
+}
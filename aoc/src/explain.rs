@@ -0,0 +1,144 @@
+//! The reasoning behind an answer, to find out why it differs from another.
+//!
+//! Solvers call [`note`] with the facts an answer rests on, such as the
+//! pairs found in the right order or the directory picked for deletion.
+//! Only [`explain`] collects them.
+//!
+//! ```
+//! use aoc::explain;
+//!
+//! let (sum, explanation) = explain::explain(|| {
+//!     let even = (1..=6).filter(|n| n % 2 == 0).collect::<Vec<_>>();
+//!     explain::note("even_numbers", || &even);
+//!     even.iter().sum::<u32>()
+//! });
+//!
+//! assert_eq!(12, sum);
+//! assert_eq!("even numbers: 2, 4, 6\n", explanation.to_string());
+//! ```
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::cell::RefCell;
+use std::fmt;
+
+/// The notes taken while solving one part, in order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Explanation {
+    notes: Vec<(&'static str, Value)>,
+}
+
+thread_local! {
+    static NOTES: RefCell<Option<Explanation>> = const { RefCell::new(None) };
+}
+
+/// Runs `solve` on this thread and returns the notes it took.
+pub fn explain<R>(solve: impl FnOnce() -> R) -> (R, Explanation) {
+    let (answer, explanation) = crate::scoped::with(&NOTES, Explanation::default(), solve);
+
+    (answer, explanation.unwrap_or_default())
+}
+
+/// Adds the value returned by `value` to the explanation, under a
+/// `snake_case` `label`, if the answer is being explained.
+pub fn note<T: Serialize>(label: &'static str, value: impl FnOnce() -> T) {
+    NOTES.with_borrow_mut(|notes| {
+        if let Some(notes) = notes {
+            let value =
+                serde_json::to_value(value()).unwrap_or_else(|error| error.to_string().into());
+            notes.notes.push((label, value));
+        }
+    });
+}
+
+impl Explanation {
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// `{"label": value, ..}`, where a later note replaces an earlier one
+    /// with the same label.
+    pub fn to_json(&self) -> Value {
+        Value::Object(
+            self.notes
+                .iter()
+                .map(|(label, value)| (label.to_string(), value.clone()))
+                .collect::<Map<_, _>>(),
+        )
+    }
+}
+
+/// One note per line, as `label: value`. Lists of numbers or strings go on
+/// the same line and other lists get a line per item.
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (label, value) in &self.notes {
+            let label = label.replace('_', " ");
+
+            match value {
+                Value::Array(items) if !items.iter().all(is_scalar) => {
+                    writeln!(f, "{label}:")?;
+                    for item in items {
+                        writeln!(f, "  {}", text(item))?;
+                    }
+                }
+                value => writeln!(f, "{label}: {}", text(value))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Array(_) | Value::Object(_))
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::Null => "none".to_string(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) if items.iter().all(is_scalar) => {
+            items.iter().map(text).collect::<Vec<_>>().join(", ")
+        }
+        Value::Object(fields) => fields
+            .iter()
+            .map(|(key, value)| match value {
+                value if is_scalar(value) => format!("{key}={}", text(value)),
+                value => format!("{key}={value}"),
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_explain() {
+        let mut evaluated = false;
+        note("skipped", || evaluated = true);
+        assert!(!evaluated);
+
+        let ((), explanation) = explain(|| {
+            note("path_length", || 31);
+            note("deleted", || json!({"path": "/d", "size": 24933642}));
+            note(
+                "top_monkeys",
+                || json!([{"monkey": 3, "inspected": 105}, {"monkey": 0, "inspected": 101}]),
+            );
+        });
+
+        assert_eq!(
+            "path length: 31\n\
+             deleted: path=/d size=24933642\n\
+             top monkeys:\n  inspected=105 monkey=3\n  inspected=101 monkey=0\n",
+            explanation.to_string()
+        );
+        assert_eq!(json!(31), explanation.to_json()["path_length"]);
+        assert!(explain(|| ()).1.is_empty());
+    }
+}
@@ -0,0 +1,18 @@
+//! Files that several runs may read and write at once.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Writes `content` to a temporary file next to `path` and renames it over
+/// `path`, so a reader never sees half of it.
+pub fn write_atomic(path: &Path, content: &str) -> Result<(), String> {
+    let error = |error: io::Error| format!("cannot write {}: {error}", path.display());
+    let partial = path.with_extension(format!("{}.tmp", std::process::id()));
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(error)?;
+    }
+    fs::write(&partial, content).map_err(error)?;
+    fs::rename(&partial, path).map_err(error)
+}
@@ -0,0 +1,120 @@
+use crate::{Answer, Report};
+use serde_json::{json, Map, Value};
+use std::str::FromStr;
+
+/// How answers are printed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("invalid format `{s}`, expected text or json")),
+        }
+    }
+}
+
+impl Answer {
+    /// Numbers stay numbers and multi-line text, such as a rendered screen,
+    /// becomes an array of its rows.
+    pub fn to_json(&self) -> Value {
+        match self {
+            Answer::Number(number) => json!(number),
+            Answer::Text(text) if text.contains('\n') => json!(text.lines().collect::<Vec<_>>()),
+            Answer::Text(text) => json!(text),
+        }
+    }
+
+    /// Reads back an answer written by [`Answer::to_json`].
+    pub fn from_json(value: &Value) -> Option<Self> {
+        match value {
+            Value::Number(number) => number.as_i64().map(Answer::Number),
+            Value::String(text) => Some(Answer::Text(text.clone())),
+            Value::Array(rows) => rows
+                .iter()
+                .map(Value::as_str)
+                .collect::<Option<Vec<_>>>()
+                .map(|rows| Answer::Text(rows.join("\n"))),
+            _ => None,
+        }
+    }
+}
+
+impl Report {
+    /// `{"day":..,"part1":..,"part2":..,"timings":..}`, with timings in
+    /// nanoseconds and only the parts that were solved.
+    pub fn to_json(&self) -> Value {
+        let mut object = Map::new();
+        let mut timings = Map::new();
+
+        object.insert("day".into(), json!(self.day));
+        timings.insert("parse_ns".into(), json!(self.parse.as_nanos() as u64));
+
+        for part in &self.parts {
+            object.insert(format!("part{}", part.part), part.answer.to_json());
+            timings.insert(
+                format!("part{}_ns", part.part),
+                json!(part.elapsed.as_nanos() as u64),
+            );
+        }
+
+        object.insert("timings".into(), Value::Object(timings));
+        Value::Object(object)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Part, PartReport};
+    use std::time::Duration;
+
+    #[test]
+    fn test_answer_to_json() {
+        assert_eq!(json!(42), Answer::Number(42).to_json());
+        assert_eq!(json!("ABC"), Answer::from("ABC").to_json());
+        assert_eq!(json!(["#..", ".#."]), Answer::from("#..\n.#.").to_json());
+    }
+
+    #[test]
+    fn test_answer_from_json() {
+        for answer in [
+            Answer::Number(-3),
+            Answer::from("ABC"),
+            Answer::from("#..\n.#."),
+        ] {
+            assert_eq!(Some(answer.clone()), Answer::from_json(&answer.to_json()));
+        }
+        assert_eq!(None, Answer::from_json(&json!({ "answer": 1 })));
+    }
+
+    #[test]
+    fn test_report_to_json() {
+        let report = Report {
+            day: 10,
+            parse: Duration::from_nanos(5),
+            parts: vec![PartReport {
+                part: Part::Two,
+                answer: Answer::from("#.\n.#"),
+                elapsed: Duration::from_micros(1),
+            }],
+        };
+
+        assert_eq!(
+            json!({
+                "day": 10,
+                "part2": ["#.", ".#"],
+                "timings": { "parse_ns": 5, "part2_ns": 1000 },
+            }),
+            report.to_json()
+        );
+    }
+}
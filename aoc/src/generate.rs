@@ -0,0 +1,177 @@
+//! Seeded input generators for stress and load testing.
+//!
+//! Each day describes the size of its inputs with named [`Knob`]s and
+//! writes inputs in exactly the format its parser accepts, so the same
+//! seed and knobs always produce the same file.
+
+use crate::input::normalize;
+use crate::rng::Rng;
+use crate::{Part, Solution};
+use std::ops::RangeInclusive;
+
+/// A size parameter of a day's generator.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Knob {
+    pub name: &'static str,
+    pub default: usize,
+    /// Values the generator can honour while keeping the input valid.
+    pub range: RangeInclusive<usize>,
+    pub help: &'static str,
+}
+
+/// Value of every knob of a generator, defaults included.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Knobs {
+    values: Vec<(&'static str, usize)>,
+}
+
+impl Knobs {
+    /// Defaults of `knobs`, with `overrides` applied on top.
+    pub fn new(knobs: &[Knob], overrides: &[(&str, usize)]) -> Result<Self, String> {
+        let mut values = knobs
+            .iter()
+            .map(|knob| (knob.name, knob.default))
+            .collect::<Vec<_>>();
+
+        for &(name, value) in overrides {
+            let knob = knobs.iter().find(|knob| knob.name == name).ok_or_else(|| {
+                let names = knobs.iter().map(|knob| knob.name).collect::<Vec<_>>();
+                format!(
+                    "unknown knob `{name}`, expected one of: {}",
+                    names.join(", ")
+                )
+            })?;
+
+            if !knob.range.contains(&value) {
+                return Err(format!(
+                    "knob `{name}` must be between {} and {}, got {value}",
+                    knob.range.start(),
+                    knob.range.end()
+                ));
+            }
+            values
+                .iter_mut()
+                .filter(|(other, _)| *other == name)
+                .for_each(|(_, current)| *current = value);
+        }
+
+        Ok(Self { values })
+    }
+
+    /// Value of the knob called `name`, which the generator must declare.
+    pub fn get(&self, name: &str) -> usize {
+        self.values
+            .iter()
+            .find(|(other, _)| *other == name)
+            .map(|&(_, value)| value)
+            .unwrap_or_else(|| panic!("no knob called `{name}`"))
+    }
+}
+
+/// A day's generator and the knobs it reads.
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub knobs: &'static [Knob],
+    pub generate: fn(&mut Rng, &Knobs) -> String,
+}
+
+impl Generator {
+    pub const fn new(knobs: &'static [Knob], generate: fn(&mut Rng, &Knobs) -> String) -> Self {
+        Self { knobs, generate }
+    }
+
+    /// The input for `seed`, with `overrides` applied to the default knobs.
+    pub fn run(&self, seed: u64, overrides: &[(&str, usize)]) -> Result<String, String> {
+        let knobs = Knobs::new(self.knobs, overrides)?;
+
+        Ok((self.generate)(&mut Rng::new(seed), &knobs))
+    }
+}
+
+/// Generates inputs for a few seeds with `overrides` applied and checks
+/// that `S` parses each one and solves both parts without panicking.
+///
+/// Also checks that each input is already normalized, since that is what
+/// the day's parser sees once the input is read from a file.
+pub fn check<S: Solution>(generator: Generator, overrides: &[(&str, usize)]) {
+    for seed in 0..8 {
+        let input = generator
+            .run(seed, overrides)
+            .unwrap_or_else(|error| panic!("{error}"));
+
+        assert_eq!(
+            normalize(&input),
+            input,
+            "seed {seed} generated an input that is not normalized"
+        );
+        assert_eq!(
+            generator.run(seed, overrides),
+            Ok(input.clone()),
+            "seed {seed} generated two different inputs"
+        );
+
+        let parsed = S::parse(&input)
+            .unwrap_or_else(|error| panic!("seed {seed} generated an invalid input at {error}"));
+        for part in Part::ALL {
+            S::solve(&parsed, part);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const KNOBS: &[Knob] = &[
+        Knob {
+            name: "lines",
+            default: 3,
+            range: 1..=10,
+            help: "number of lines",
+        },
+        Knob {
+            name: "width",
+            default: 5,
+            range: 1..=80,
+            help: "characters per line",
+        },
+    ];
+
+    fn lines(rng: &mut Rng, knobs: &Knobs) -> String {
+        (0..knobs.get("lines"))
+            .map(|_| {
+                let line = (0..knobs.get("width"))
+                    .map(|_| char::from(b'a' + rng.below(26) as u8))
+                    .collect::<String>();
+                line + "\n"
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_knobs() {
+        let knobs = Knobs::new(KNOBS, &[("width", 7)]).unwrap();
+
+        assert_eq!(3, knobs.get("lines"));
+        assert_eq!(7, knobs.get("width"));
+        assert_eq!(
+            Err("unknown knob `height`, expected one of: lines, width".to_string()),
+            Knobs::new(KNOBS, &[("height", 7)])
+        );
+        assert_eq!(
+            Err("knob `lines` must be between 1 and 10, got 0".to_string()),
+            Knobs::new(KNOBS, &[("lines", 0)])
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let generator = Generator::new(KNOBS, lines);
+        let input = generator.run(42, &[("lines", 2)]).unwrap();
+
+        assert_eq!(2, input.lines().count());
+        assert!(input.lines().all(|line| line.len() == 5));
+        assert_eq!(Ok(input), generator.run(42, &[("lines", 2)]));
+        assert_ne!(generator.run(42, &[]), generator.run(43, &[]));
+    }
+}
@@ -0,0 +1,130 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// File name of the real puzzle input inside a day directory.
+pub const INPUT_FILE: &str = "input.txt";
+/// File name of the puzzle example inside a day directory.
+pub const TEST_FILE: &str = "examples/test-input.txt";
+
+/// Where a puzzle input is read from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum InputSource {
+    /// The `input.txt` shipped next to the day's manifest.
+    #[default]
+    Bundled,
+    /// The `examples/test-input.txt` shipped next to the day's manifest.
+    Test,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets an `--input` argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    /// Reads the whole input, resolving bundled files against `day_dir`,
+    /// and [normalizes](normalize) it.
+    pub fn read(&self, day_dir: &Path) -> Result<String, InputError> {
+        self.read_raw(day_dir).map(|input| normalize(&input))
+    }
+
+    fn read_raw(&self, day_dir: &Path) -> Result<String, InputError> {
+        let path = match self {
+            InputSource::Bundled => day_dir.join(INPUT_FILE),
+            InputSource::Test => day_dir.join(TEST_FILE),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => {
+                let mut input = String::new();
+
+                return io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|source| InputError {
+                        origin: "<stdin>".to_string(),
+                        source,
+                    });
+            }
+        };
+
+        std::fs::read_to_string(&path).map_err(|source| InputError {
+            origin: path.display().to_string(),
+            source,
+        })
+    }
+}
+
+/// Input as every parser expects it: no byte order mark, `\n` line endings,
+/// no trailing whitespace on any line, and a single newline at the end.
+pub fn normalize(raw: &str) -> String {
+    let text = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    let mut normalized = String::with_capacity(text.len());
+
+    for line in text.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+
+    normalized
+}
+
+/// An input that could not be read.
+#[derive(Debug)]
+pub struct InputError {
+    origin: String,
+    source: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot read input `{}`: {}", self.origin, self.source)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::File(PathBuf::from("other.txt")),
+            InputSource::from_arg("other.txt")
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let error = InputSource::Bundled
+            .read(Path::new("does-not-exist"))
+            .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("cannot read input `does-not-exist/input.txt`"));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!("a\n\nb\n", normalize("\u{feff}a\r\n\r\nb  \r\n\r\n\n"));
+        assert_eq!("a\n  b\n", normalize("a\n  b"));
+        assert_eq!("", normalize("\r\n\n"));
+    }
+}
@@ -0,0 +1,258 @@
+//! Shared plumbing for the daily puzzle crates.
+//!
+//! Every `day-NN` crate implements [`Solution`] so tooling can parse an
+//! input and solve either part the same way regardless of the day.
+
+pub mod alloc;
+mod answer;
+pub mod bench;
+pub mod cancel;
+pub mod checkpoint;
+pub mod differential;
+mod error;
+pub mod examples;
+pub mod explain;
+pub mod files;
+mod format;
+pub mod generate;
+pub mod input;
+pub mod params;
+pub mod parse;
+mod puzzle;
+pub mod reference;
+pub mod rng;
+mod scoped;
+mod solution;
+pub mod trace;
+pub mod visual;
+
+pub use answer::{Answer, Expected};
+pub use error::ParseError;
+pub use format::Format;
+pub use input::InputSource;
+pub use puzzle::{Entry, PartReport, Puzzle, Report};
+pub use solution::{Part, Solution};
+
+use std::path::Path;
+use std::process::ExitCode;
+
+/// Entry point of the `day-NN` binaries.
+///
+/// Reads the input named by `--input <path>` (`-` for stdin), the example
+/// with `--test`, or else the `input.txt` in `day_dir`, then prints both
+/// answers on separate lines, or as a JSON object with `--format json`.
+/// `-v`, `-vv` or `-vvv` print [trace](mod@trace) events on stderr, only from
+/// the modules named by `--trace-filter` if given. Puzzle [parameters](params)
+/// come from the `aoc.toml` above `day_dir`, then from `--param NAME=VALUE`.
+/// `--explain` also prints the [notes](explain) each part takes.
+pub fn main<S: Solution>(day_dir: &str) -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("error: {message}");
+            eprintln!(
+                "usage: day-{:02} [--input <path> | --test] [--param NAME=VALUE] [--format text|json] [--explain] [-v|-vv|-vvv] [--trace-filter <module>]",
+                S::DAY
+            );
+            return ExitCode::from(2);
+        }
+    };
+    if let Err(message) = trace::init(args.verbosity, &args.trace_filters) {
+        eprintln!("error: {message}");
+        return ExitCode::from(2);
+    }
+    let day_dir = Path::new(day_dir);
+    let params = params::Config::load(&day_dir.join("..").join(params::CONFIG_FILE))
+        .and_then(|config| config.params(S::PARAMS, S::DAY, day_dir, &args.source, &args.params));
+    let params = match params {
+        Ok(params) => params,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::from(2);
+        }
+    };
+    let input = match args.source.read(day_dir) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let solve = |parts: &[Part]| Entry::<S>::new().run(&input, &params, parts);
+    let mut explanations = Vec::new();
+    let report = match args.explain {
+        // Each part runs on its own to tell their notes apart
+        true => Part::ALL.iter().try_fold(
+            Report {
+                day: S::DAY,
+                parse: Default::default(),
+                parts: vec![],
+            },
+            |mut report, &part| {
+                let (solved, explanation) = explain::explain(|| solve(&[part]));
+                let solved = solved?;
+                report.parse = solved.parse;
+                report.parts.extend(solved.parts);
+                explanations.push(explanation);
+                Ok(report)
+            },
+        ),
+        false => solve(&Part::ALL),
+    };
+    let report = match report {
+        Ok(report) => report,
+        Err(error) => {
+            eprintln!("error: invalid input at {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match args.format {
+        Format::Text => {
+            for (i, part) in report.parts.iter().enumerate() {
+                println!("{}", part.answer);
+                if let Some(explanation) = explanations.get(i) {
+                    explanation
+                        .to_string()
+                        .lines()
+                        .for_each(|line| println!("  {line}"));
+                }
+            }
+        }
+        Format::Json => {
+            let mut json = report.to_json();
+            if args.explain {
+                json["explanation"] = report
+                    .parts
+                    .iter()
+                    .zip(&explanations)
+                    .map(|(part, explanation)| {
+                        (format!("part{}", part.part), explanation.to_json())
+                    })
+                    .collect();
+            }
+            println!("{json}");
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    source: InputSource,
+    format: Format,
+    verbosity: u8,
+    trace_filters: Vec<String>,
+    params: Vec<(String, i64)>,
+    explain: bool,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+
+    while let Some(arg) = args.next() {
+        let (name, inline) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or(format!("missing value for `{name}`"))
+        };
+
+        match name {
+            "--input" => parsed.source = InputSource::from_arg(&value()?),
+            "--format" => parsed.format = value()?.parse()?,
+            "--test" if inline.is_none() => parsed.source = InputSource::Test,
+            "--explain" if inline.is_none() => parsed.explain = true,
+            "--trace-filter" => parsed.trace_filters.push(value()?),
+            "--param" => parsed.params.push(params::parse_override(&value()?)?),
+            "-v" | "-vv" | "-vvv" if inline.is_none() => parsed.verbosity = name.len() as u8 - 1,
+            _ => return Err(format!("unexpected argument `{arg}`")),
+        }
+    }
+
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<InputSource, String> {
+        parse_args(args.iter().map(|arg| arg.to_string())).map(|args| args.source)
+    }
+
+    fn format(args: &[&str]) -> Result<Format, String> {
+        parse_args(args.iter().map(|arg| arg.to_string())).map(|args| args.format)
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(Ok(InputSource::Bundled), parse(&[]));
+        assert_eq!(Ok(InputSource::Test), parse(&["--test"]));
+        assert_eq!(Ok(InputSource::Stdin), parse(&["--input", "-"]));
+        assert_eq!(
+            Ok(InputSource::File(PathBuf::from("a.txt"))),
+            parse(&["--input=a.txt"])
+        );
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--verbose"]).is_err());
+        assert!(parse(&["--test=yes"]).is_err());
+        assert!(format(&["--format", "yaml"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(Ok(Format::Text), format(&["--test"]));
+        assert_eq!(Ok(Format::Json), format(&["--format", "json"]));
+        assert_eq!(Ok(Format::Json), format(&["--format=json", "--test"]));
+    }
+
+    #[test]
+    fn test_parse_verbosity() {
+        let parse = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string())).unwrap();
+
+        assert_eq!(0, parse(&["--test"]).verbosity);
+        assert_eq!(2, parse(&["-vv"]).verbosity);
+        assert_eq!(
+            vec!["7".to_string(), "day_07::generate".to_string()],
+            parse(&[
+                "-v",
+                "--trace-filter",
+                "7",
+                "--trace-filter=day_07::generate"
+            ])
+            .trace_filters
+        );
+        assert!(parse_args(["-vvvv".to_string()].into_iter()).is_err());
+    }
+
+    #[test]
+    fn test_parse_params() {
+        let parse = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(
+            Ok(vec![("row".to_string(), 10), ("max".to_string(), 20)]),
+            parse(&["--param", "row=10", "--param=max=20"]).map(|args| args.params)
+        );
+        assert!(parse(&["--param", "row"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_explain() {
+        let parse = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(Ok(false), parse(&["--test"]).map(|args| args.explain));
+        assert_eq!(Ok(true), parse(&["--explain"]).map(|args| args.explain));
+        assert!(parse(&["--explain=yes"]).is_err());
+    }
+}
@@ -0,0 +1,337 @@
+//! Puzzle parameters that differ between inputs.
+//!
+//! Some puzzles ask about a given row, number of rounds or disk size, and
+//! their examples ask about other ones than the real input. Each day
+//! declares these as [`Param`]s defaulting to the values of the real
+//! puzzle, which are overridden in turn by `aoc.toml` at the root of the
+//! workspace, by the sidecar of an example and by `--param NAME=VALUE`.
+//!
+//! ```toml
+//! # Every input of day 15
+//! [day-15]
+//! row = 2000000
+//!
+//! # Only this input, relative to the day directory
+//! [day-15."inputs/other.txt"]
+//! row = 10
+//! max = 20
+//! ```
+
+use crate::cancel::Timeouts;
+use crate::examples;
+use crate::input::{InputSource, INPUT_FILE, TEST_FILE};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::ErrorKind;
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+/// File name of the parameters, at the root of the workspace.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// A value the puzzle states, such as the row to look at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value the real puzzle asks for.
+    pub default: i64,
+    /// Values the solver can honour.
+    pub range: RangeInclusive<i64>,
+    pub help: &'static str,
+}
+
+/// Value of every parameter of a day, defaults included.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Params {
+    values: Vec<(&'static str, i64)>,
+}
+
+impl Params {
+    /// Defaults of `params`, with `overrides` applied on top in order.
+    pub fn new(params: &[Param], overrides: &[(&str, i64)]) -> Result<Self, String> {
+        let mut values = Self::defaults(params).values;
+
+        for &(name, value) in overrides {
+            let Some(param) = params.iter().find(|param| param.name == name) else {
+                return Err(unknown(params, name));
+            };
+
+            if !param.range.contains(&value) {
+                return Err(format!(
+                    "parameter `{name}` must be between {} and {}, got {value}",
+                    param.range.start(),
+                    param.range.end()
+                ));
+            }
+            values
+                .iter_mut()
+                .filter(|(other, _)| *other == name)
+                .for_each(|(_, current)| *current = value);
+        }
+
+        Ok(Self { values })
+    }
+
+    /// The values of the real puzzle.
+    pub fn defaults(params: &[Param]) -> Self {
+        Self {
+            values: params
+                .iter()
+                .map(|param| (param.name, param.default))
+                .collect(),
+        }
+    }
+
+    /// Value of the parameter called `name`, which the day must declare.
+    ///
+    /// # Panics
+    ///
+    /// If there is no such parameter, or its range does not fit `T`.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> T {
+        let value = self
+            .values
+            .iter()
+            .find(|(other, _)| *other == name)
+            .map(|&(_, value)| value)
+            .unwrap_or_else(|| panic!("no parameter called `{name}`"));
+
+        T::try_from(value).unwrap_or_else(|_| panic!("parameter `{name}` is out of range: {value}"))
+    }
+}
+
+fn unknown(params: &[Param], name: &str) -> String {
+    match params.iter().map(|param| param.name).collect::<Vec<_>>() {
+        names if names.is_empty() => format!("unknown parameter `{name}`, there are none"),
+        names => format!(
+            "unknown parameter `{name}`, expected one of: {}",
+            names.join(", ")
+        ),
+    }
+}
+
+impl fmt::Display for Params {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, (name, value)) in self.values.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{name}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Contents of `aoc.toml`: parameters keyed by day directory, then
+/// optionally by input file inside it, and the time budgets of the solvers.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// See [`cancel`](crate::cancel).
+    #[serde(default)]
+    pub timeouts: Timeouts,
+    #[serde(flatten)]
+    days: BTreeMap<String, BTreeMap<String, Entry>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Entry {
+    Param(i64),
+    Input(BTreeMap<String, i64>),
+}
+
+impl Config {
+    /// Reads the file at `path`; a missing file sets nothing.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|error| format!("cannot parse `{}`: {error}", path.display())),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("cannot read `{}`: {error}", path.display())),
+        }
+    }
+
+    fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// Values set for every input of `day`, then those set for `file`.
+    fn overrides(&self, day: u8, file: Option<&str>) -> Vec<(&str, i64)> {
+        let Some(entries) = self.days.get(&format!("day-{day:02}")) else {
+            return vec![];
+        };
+        let for_file = file
+            .and_then(|file| match entries.get(file) {
+                Some(Entry::Input(values)) => Some(values),
+                _ => None,
+            })
+            .into_iter()
+            .flatten()
+            .map(|(name, &value)| (name.as_str(), value));
+
+        entries
+            .iter()
+            .filter_map(|(name, entry)| match entry {
+                Entry::Param(value) => Some((name.as_str(), *value)),
+                Entry::Input(_) => None,
+            })
+            .chain(for_file)
+            .collect()
+    }
+
+    /// Parameters `declared` by `day` for reading `source` from `day_dir`:
+    /// the defaults, then this file, then the sidecar of the example, then
+    /// `overrides` from the command line.
+    pub fn params(
+        &self,
+        declared: &[Param],
+        day: u8,
+        day_dir: &Path,
+        source: &InputSource,
+        overrides: &[(String, i64)],
+    ) -> Result<Params, String> {
+        let sidecar = match source {
+            InputSource::Test => examples::sidecar_params(&day_dir.join(TEST_FILE))?,
+            _ => vec![],
+        };
+        let file = file_name(source, day_dir);
+        let overrides = self
+            .overrides(day, file.as_deref())
+            .into_iter()
+            .chain(
+                sidecar
+                    .iter()
+                    .chain(overrides)
+                    .map(|(name, value)| (name.as_str(), *value)),
+            )
+            .collect::<Vec<_>>();
+
+        Params::new(declared, &overrides).map_err(|error| format!("day {day:02}: {error}"))
+    }
+}
+
+/// Name of the file `source` reads, relative to `day_dir` when inside it.
+fn file_name(source: &InputSource, day_dir: &Path) -> Option<String> {
+    match source {
+        InputSource::Bundled => Some(INPUT_FILE.to_string()),
+        InputSource::Test => Some(TEST_FILE.to_string()),
+        InputSource::File(path) => {
+            let relative = match (path.canonicalize(), day_dir.canonicalize()) {
+                (Ok(path), Ok(day_dir)) => path.strip_prefix(day_dir).ok().map(Path::to_path_buf),
+                _ => None,
+            };
+            Some(relative.as_deref().unwrap_or(path).display().to_string())
+        }
+        InputSource::Stdin => None,
+    }
+}
+
+/// Reads a `NAME=VALUE` argument, where the value may use `_` separators.
+pub fn parse_override(arg: &str) -> Result<(String, i64), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or(format!("expected `NAME=VALUE`, found `{arg}`"))?;
+    let value = value
+        .replace('_', "")
+        .parse()
+        .map_err(|_| format!("invalid value `{value}` for parameter `{name}`"))?;
+
+    Ok((name.to_string(), value))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PARAMS: &[Param] = &[
+        Param {
+            name: "row",
+            default: 2_000_000,
+            range: i64::MIN..=i64::MAX,
+            help: "row to count",
+        },
+        Param {
+            name: "max",
+            default: 4_000_000,
+            range: 0..=i64::MAX,
+            help: "largest coordinate",
+        },
+    ];
+
+    const CONFIG: &str = r#"
+[day-15]
+row = 11
+
+[day-15."inputs/small.txt"]
+row = 10
+max = 20
+"#;
+
+    #[test]
+    fn test_params() {
+        let params = Params::new(PARAMS, &[("max", 20), ("max", 30)]).unwrap();
+
+        assert_eq!(2_000_000, params.get::<isize>("row"));
+        assert_eq!(30, params.get::<usize>("max"));
+        assert_eq!("row=2000000 max=30", params.to_string());
+        assert_eq!(
+            Err("unknown parameter `rows`, expected one of: row, max".to_string()),
+            Params::new(PARAMS, &[("rows", 7)])
+        );
+        assert_eq!(
+            Err("parameter `max` must be between 0 and 9223372036854775807, got -1".to_string()),
+            Params::new(PARAMS, &[("max", -1)])
+        );
+        assert!(Params::new(&[], &[("max", 1)]).is_err());
+    }
+
+    #[test]
+    fn test_config() {
+        let config = Config::parse(CONFIG).unwrap();
+        let day_dir = Path::new("day-15");
+        let params = |source: InputSource, overrides: &[(String, i64)]| {
+            config
+                .params(PARAMS, 15, day_dir, &source, overrides)
+                .map(|params| params.to_string())
+        };
+
+        assert_eq!(
+            Ok("row=11 max=4000000".to_string()),
+            params(InputSource::Bundled, &[])
+        );
+        assert_eq!(
+            Ok("row=10 max=20".to_string()),
+            params(InputSource::from_arg("inputs/small.txt"), &[])
+        );
+        assert_eq!(
+            Ok("row=11 max=5".to_string()),
+            params(InputSource::Stdin, &[("max".to_string(), 5)])
+        );
+        assert_eq!(
+            Ok("row=2000000 max=4000000".to_string()),
+            Config::default()
+                .params(PARAMS, 15, day_dir, &InputSource::Bundled, &[])
+                .map(|params| params.to_string())
+        );
+        assert!(Config::parse("[day-15]\nrow = \"ten\"\n").is_err());
+        assert!(Config::parse("[timeouts]\nall = -1\n").is_err());
+        assert_eq!(
+            Some(std::time::Duration::from_secs(30)),
+            Config::parse("[timeouts]\n\"15.2\" = 30\n")
+                .unwrap()
+                .timeouts
+                .get(15, crate::Part::Two)
+        );
+    }
+
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(
+            Ok(("row".to_string(), 2_000_000)),
+            parse_override("row=2_000_000")
+        );
+        assert_eq!(Ok(("row".to_string(), -3)), parse_override("row=-3"));
+        assert!(parse_override("row").is_err());
+        assert!(parse_override("row=ten").is_err());
+    }
+}
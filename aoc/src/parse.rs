@@ -0,0 +1,239 @@
+//! Helpers for the input formats the puzzles share.
+//!
+//! Everything here remembers which line it came from, so each helper can
+//! report a [`ParseError`] pointing at the offending text.
+
+use crate::ParseError;
+use std::str::FromStr;
+
+/// A line of input and its index, starting at 0.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+/// Lines of `input`, with their indices.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+impl<'a> Line<'a> {
+    pub fn new(index: usize, text: &'a str) -> Self {
+        Self { index, text }
+    }
+
+    /// Error at `column`, starting at 1.
+    pub fn error(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.index, column, self.text, message)
+    }
+
+    /// Error pointing at `token`, which must be a slice of the line.
+    pub fn error_at(&self, token: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at_token(self.index, self.text, token, message)
+    }
+
+    /// Parses the whole line with a [`FromStr`] that reports errors as if
+    /// the line were the first one.
+    pub fn parse<T: FromStr<Err = ParseError>>(&self) -> Result<T, ParseError> {
+        self.text
+            .parse()
+            .map_err(|error: ParseError| error.shift(self.index))
+    }
+
+    /// Parses `token`, a slice of the line, failing with "invalid `what`".
+    pub fn token<T: FromStr>(&self, token: &str, what: &str) -> Result<T, ParseError> {
+        token
+            .parse()
+            .map_err(|_| self.error_at(token, format!("invalid {what} `{token}`")))
+    }
+
+    /// Every integer in the line, in order. A `-` is a sign only when it
+    /// does not follow a digit, so `2-4` holds 2 and 4 but `x=-4` holds -4.
+    pub fn integers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        let bytes = self.text.as_bytes();
+        let mut integers = vec![];
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let signed = bytes[i] == b'-'
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+                && (i == 0 || !bytes[i - 1].is_ascii_digit());
+
+            if !signed && !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            i += 1;
+            while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+                i += 1;
+            }
+            integers.push(self.token(&self.text[start..i], "number")?);
+        }
+
+        Ok(integers)
+    }
+
+    /// Exactly `N` [`integers`](Self::integers), failing with
+    /// "expected `N` `what`" otherwise.
+    pub fn integers_n<T: FromStr, const N: usize>(&self, what: &str) -> Result<[T; N], ParseError> {
+        self.integers()?.try_into().map_err(|integers: Vec<T>| {
+            self.error(1, format!("expected {N} {what}, found {}", integers.len()))
+        })
+    }
+
+    /// The line cut into fields of `width` bytes; the last may be shorter.
+    pub fn columns(&self, width: usize) -> impl Iterator<Item = &'a str> {
+        let text = self.text;
+
+        (0..text.len())
+            .step_by(width.max(1))
+            .map(move |start| text.get(start..text.len().min(start + width)).unwrap_or(""))
+    }
+
+    /// Points of an `x,y -> x,y -> ...` path.
+    pub fn path<T: FromStr>(&self) -> Result<Vec<(T, T)>, ParseError> {
+        self.text
+            .split(" -> ")
+            .map(|point| {
+                let invalid = || self.error_at(point, format!("invalid position `{point}`"));
+                let (x, y) = point.split_once(',').ok_or_else(invalid)?;
+
+                Ok((
+                    x.trim().parse().map_err(|_| invalid())?,
+                    y.trim().parse().map_err(|_| invalid())?,
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Lines between blank lines, and the index of the first one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Paragraph<'a> {
+    pub start: usize,
+    pub text: &'a str,
+}
+
+/// Paragraphs of `input`, skipping any run of blank lines between them.
+pub fn paragraphs(input: &str) -> impl Iterator<Item = Paragraph<'_>> {
+    let mut lines = lines(input).peekable();
+
+    std::iter::from_fn(move || {
+        let first = lines.find(|line| !line.text.is_empty())?;
+        let mut last = first;
+
+        while let Some(line) = lines.next_if(|line| !line.text.is_empty()) {
+            last = line;
+        }
+
+        let offset = |line: Line| line.text.as_ptr() as usize - input.as_ptr() as usize;
+        let end = offset(last) + last.text.len();
+
+        Some(Paragraph {
+            start: first.index,
+            text: &input[offset(first)..end],
+        })
+    })
+}
+
+impl<'a> Paragraph<'a> {
+    /// Lines of the paragraph, with their indices within the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = Line<'a>> {
+        let start = self.start;
+
+        lines(self.text).map(move |line| Line::new(start + line.index, line.text))
+    }
+
+    /// Parses the paragraph with a [`FromStr`] that reports errors as if
+    /// the paragraph started on the first line.
+    pub fn parse<T: FromStr<Err = ParseError>>(&self) -> Result<T, ParseError> {
+        self.text
+            .parse()
+            .map_err(|error: ParseError| error.shift(self.start))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        let line = Line::new(0, "Sensor at x=2, y=-18: beacon 2-4,6-8");
+
+        assert_eq!(Ok(vec![2, -18, 2, 4, 6, 8]), line.integers::<i32>());
+        assert_eq!(
+            Ok([2, -18]),
+            Line::new(0, "x=2, y=-18").integers_n::<i32, 2>("coordinates")
+        );
+        assert_eq!(
+            "expected 3 coordinates, found 2",
+            Line::new(0, "x=2, y=-18")
+                .integers_n::<i32, 3>("coordinates")
+                .unwrap_err()
+                .message
+        );
+        assert_eq!(
+            ParseError::at(4, 8, "move 1 -3", "invalid number `-3`"),
+            Line::new(4, "move 1 -3").integers::<u32>().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_columns() {
+        let line = Line::new(0, "[A]     [C]");
+
+        assert_eq!(
+            vec!["[A] ", "    ", "[C]"],
+            line.columns(4).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_path() {
+        let line = Line::new(2, "498,4 -> 498,6 -> 496,x");
+
+        assert_eq!(
+            Ok(vec![(498, 4), (498, 6)]),
+            Line::new(0, "498,4 -> 498,6").path::<usize>()
+        );
+        assert_eq!(
+            ParseError::at(2, 19, line.text, "invalid position `496,x`"),
+            line.path::<usize>().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let input = "a\nb\n\n\nc\n\nd\ne\n";
+        let paragraphs = paragraphs(input).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                Paragraph {
+                    start: 0,
+                    text: "a\nb"
+                },
+                Paragraph {
+                    start: 4,
+                    text: "c"
+                },
+                Paragraph {
+                    start: 6,
+                    text: "d\ne"
+                },
+            ],
+            paragraphs
+        );
+        assert_eq!(
+            vec![Line::new(6, "d"), Line::new(7, "e")],
+            paragraphs[2].lines().collect::<Vec<_>>()
+        );
+    }
+}
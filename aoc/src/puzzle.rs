@@ -0,0 +1,144 @@
+use crate::alloc::{self, AllocReport};
+use crate::bench::{BenchOptions, BenchReport, Phase, Samples};
+use crate::params::{Param, Params};
+use crate::{Answer, ParseError, Part, Solution};
+use std::hint::black_box;
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+/// Outcome of solving one part, with the wall-clock time it took.
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Outcome of a single run over one input.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub day: u8,
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+/// Object-safe view of a [`Solution`], so days can be listed and run
+/// without knowing their input and answer types.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    /// Parameters of the day, see [`Solution::PARAMS`].
+    fn params(&self) -> &'static [Param];
+
+    /// Parses `input` once and solves each of `parts` in order.
+    fn run(&self, input: &str, params: &Params, parts: &[Part]) -> Result<Report, ParseError>;
+
+    /// Times parsing and each part separately over repeated runs.
+    fn bench(
+        &self,
+        input: &str,
+        params: &Params,
+        options: &BenchOptions,
+    ) -> Result<BenchReport, ParseError>;
+
+    /// Counts the allocations of parsing and each part, see [`alloc`].
+    fn profile(&self, input: &str, params: &Params) -> Result<AllocReport, ParseError>;
+}
+
+/// Adapts a [`Solution`] into a [`Puzzle`].
+pub struct Entry<S>(PhantomData<fn() -> S>);
+
+impl<S> Entry<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for Entry<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Solution> Puzzle for Entry<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn run(&self, input: &str, params: &Params, parts: &[Part]) -> Result<Report, ParseError> {
+        let start = Instant::now();
+        let input = S::parse_with(input, params)?;
+        let parse = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = S::solve(&input, part);
+
+                PartReport {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(Report {
+            day: S::DAY,
+            parse,
+            parts,
+        })
+    }
+
+    fn bench(
+        &self,
+        input: &str,
+        params: &Params,
+        options: &BenchOptions,
+    ) -> Result<BenchReport, ParseError> {
+        let parsed = S::parse_with(input, params)?;
+        let parse = Samples::collect(options, || {
+            black_box(S::parse_with(black_box(input), params).ok());
+        });
+        let mut phases = vec![(Phase::Parse, parse)];
+
+        for part in Part::ALL {
+            let samples = Samples::collect(options, || {
+                black_box(S::solve(black_box(&parsed), part));
+            });
+            phases.push((Phase::Solve(part), samples));
+        }
+
+        Ok(BenchReport {
+            day: S::DAY,
+            phases,
+        })
+    }
+
+    fn profile(&self, input: &str, params: &Params) -> Result<AllocReport, ParseError> {
+        let (parsed, allocations) = alloc::measure(|| S::parse_with(input, params));
+        let parsed = parsed?;
+        let mut phases = vec![(Phase::Parse, allocations)];
+
+        for part in Part::ALL {
+            let (_, allocations) = alloc::measure(|| S::solve(&parsed, part));
+            phases.push((Phase::Solve(part), allocations));
+        }
+
+        Ok(AllocReport {
+            day: S::DAY,
+            phases,
+        })
+    }
+}
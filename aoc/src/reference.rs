@@ -0,0 +1,31 @@
+//! Checks a day against the naive solver it ships for
+//! [differential](crate::differential) testing.
+
+use crate::differential::{compare, Options};
+use crate::params::Params;
+use crate::rng::Rng;
+use crate::{Answer, Part, Solution};
+
+/// Solves the inputs from `generate` with `S`, parsed with `params` over
+/// the defaults, and with `naive`, and panics with the first input they
+/// disagree on, shrunk.
+pub fn check<S: Solution, A: Into<Answer>, B: Into<Answer>>(
+    params: &[(&str, i64)],
+    generate: impl FnMut(&mut Rng) -> String,
+    naive: impl Fn(&str) -> (A, B),
+) {
+    let params = Params::new(S::PARAMS, params).unwrap_or_else(|error| panic!("{error}"));
+    let solve = |input: &str| {
+        let parsed = S::parse_with(input, &params)
+            .unwrap_or_else(|error| panic!("invalid input at {error}"));
+        (S::solve(&parsed, Part::One), S::solve(&parsed, Part::Two))
+    };
+    let naive = |input: &str| {
+        let (part1, part2) = naive(input);
+        (part1.into(), part2.into())
+    };
+
+    if let Err(mismatch) = compare(Options::default(), generate, solve, naive) {
+        panic!("{mismatch}");
+    }
+}
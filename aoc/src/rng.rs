@@ -0,0 +1,89 @@
+//! A small seeded random number generator for generated inputs.
+//!
+//! Not suitable for anything but tests: the point is that the same seed
+//! always produces the same input, on every platform.
+
+use std::ops::RangeInclusive;
+
+/// SplitMix64, which turns any seed, including 0, into a usable stream.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "cannot pick below 0");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick from {start}..={end}");
+
+        let span = end.abs_diff(start).wrapping_add(1);
+        match span {
+            0 => self.next_u64() as i64,
+            _ => start.wrapping_add((self.next_u64() % span) as i64),
+        }
+    }
+
+    /// True once in `n` times on average.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    /// An element of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Puts `items` in a random order.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..8).map(|_| rng.range(-3..=3)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|n| (-3..=3).contains(n)));
+    }
+
+    #[test]
+    fn test_range_edges() {
+        let mut rng = Rng::new(0);
+
+        assert_eq!(5, rng.range(5..=5));
+        assert_eq!(i64::MAX, rng.range(i64::MAX..=i64::MAX));
+        // The full range spans 2^64 values, which wraps to 0
+        rng.range(i64::MIN..=i64::MAX);
+    }
+}
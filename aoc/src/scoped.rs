@@ -0,0 +1,31 @@
+//! Thread-locals that hold a value for the length of one call.
+
+use std::cell::RefCell;
+use std::thread::LocalKey;
+
+type Key<T> = &'static LocalKey<RefCell<Option<T>>>;
+
+struct Restore<T: 'static> {
+    key: Key<T>,
+    previous: Option<T>,
+}
+
+impl<T> Drop for Restore<T> {
+    fn drop(&mut self) {
+        self.key.set(self.previous.take());
+    }
+}
+
+/// Runs `f` with `key` holding `value` and returns what `key` holds once
+/// it is done. What `key` held before is put back, even if `f` unwinds.
+pub(crate) fn with<T, R>(key: Key<T>, value: T, f: impl FnOnce() -> R) -> (R, Option<T>) {
+    let restore = Restore {
+        key,
+        previous: key.replace(Some(value)),
+    };
+    let result = f();
+    let last = key.take();
+    drop(restore);
+
+    (result, last)
+}
@@ -0,0 +1,78 @@
+use crate::params::{Param, Params};
+use crate::{Answer, ParseError};
+use std::fmt;
+use std::str::FromStr;
+
+/// One of the two halves of a puzzle.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// 1 or 2.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part `{s}`, expected 1 or 2")),
+        }
+    }
+}
+
+/// A day of the calendar: parses its puzzle input once and solves both parts.
+pub trait Solution {
+    /// Day of the calendar, starting at 1.
+    const DAY: u8;
+    /// Puzzle title as shown on the calendar.
+    const TITLE: &'static str;
+    /// Values the puzzle states that another input may ask about
+    /// differently, see [`params`](crate::params).
+    const PARAMS: &'static [Param] = &[];
+
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses `input` to be solved with `params`, which hold a value for
+    /// each of [`PARAMS`](Self::PARAMS). Days with parameters keep them in
+    /// their input and parse with the defaults in [`parse`](Self::parse).
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// Solves a single part, converting its typed answer.
+    fn solve(input: &Self::Input, part: Part) -> Answer {
+        match part {
+            Part::One => Self::part1(input).into(),
+            Part::Two => Self::part2(input).into(),
+        }
+    }
+}
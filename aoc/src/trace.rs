@@ -0,0 +1,177 @@
+//! Structured trace events from inside the solvers.
+//!
+//! Solvers call [`trace!`](crate::trace!) with a [`Level`], an event name
+//! and `key = value` fields. Nothing is formatted, or even evaluated, until
+//! [`init`] turns on that level for the module the event comes from, and a
+//! disabled event costs a single relaxed atomic load.
+//!
+//! ```
+//! aoc::trace!(Debug, "cd", dir = "a", depth = 1);
+//! ```
+
+use std::fmt;
+use std::io::Write;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+/// How much detail an event adds, turned on by `-v`, `-vv` and `-vvv`.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    /// Milestones and results: a few events per part.
+    Info = 1,
+    /// Steps of the algorithm: a directory, a round, a row.
+    Debug = 2,
+    /// Every single operation: a throw, a queued neighbor.
+    Trace = 3,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// Highest level turned on, 0 when tracing is off.
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+/// Module path prefixes allowed to trace, every module when empty.
+static FILTERS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+
+/// Turns on events up to `verbosity` (1 to 3, higher values count as 3)
+/// from the modules matching `filters`, or from every module if there are
+/// none.
+///
+/// A filter is a day number such as `7`, a crate such as `day-07`, or a
+/// module path such as `day_12::generate`, and matches that module and
+/// everything inside it.
+pub fn init(verbosity: u8, filters: &[String]) -> Result<(), String> {
+    let filters = filters
+        .iter()
+        .map(|filter| parse_filter(filter))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    *FILTERS.write().unwrap_or_else(|error| error.into_inner()) = filters;
+    VERBOSITY.store(verbosity.min(Level::Trace as u8), Ordering::Relaxed);
+
+    Ok(())
+}
+
+fn parse_filter(filter: &str) -> Result<String, String> {
+    if let Ok(day) = filter.parse::<u8>() {
+        return Ok(format!("day_{day:02}"));
+    }
+
+    let path = filter.replace('-', "_");
+    let valid = path
+        .split("::")
+        .all(|name| !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_'));
+
+    match valid {
+        true => Ok(path),
+        false => Err(format!(
+            "invalid trace filter `{filter}`, expected a day number or a module path"
+        )),
+    }
+}
+
+fn matches(filters: &[String], module: &str) -> bool {
+    filters.is_empty()
+        || filters.iter().any(|filter| {
+            module
+                .strip_prefix(filter.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        })
+}
+
+/// Whether [`init`] turned on any events at all.
+pub fn active() -> bool {
+    VERBOSITY.load(Ordering::Relaxed) > 0
+}
+
+/// Whether events at `level` from `module` are shown.
+#[inline(always)]
+pub fn enabled(level: Level, module: &str) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level as u8 && allowed(module)
+}
+
+// Kept out of line so that disabled events stay a load and a branch
+#[cold]
+#[inline(never)]
+fn allowed(module: &str) -> bool {
+    let filters = FILTERS.read().unwrap_or_else(|error| error.into_inner());
+    matches(&filters, module)
+}
+
+/// Writes an event as a single line on stderr. Call it through
+/// [`trace!`](crate::trace!), which checks [`enabled`] first.
+#[cold]
+#[inline(never)]
+pub fn emit(level: Level, module: &str, event: &str, fields: &[(&str, &dyn fmt::Debug)]) {
+    let mut line = format!("{level:<5} {module}: {event}");
+
+    for (key, value) in fields {
+        line.push_str(&format!(" {key}={value:?}"));
+    }
+    line.push('\n');
+
+    let _ = std::io::stderr().lock().write_all(line.as_bytes());
+}
+
+/// Emits a trace event with `key = value` fields if its level is enabled
+/// for the calling module. The fields are only evaluated in that case, and
+/// a field given as just `key` takes the value of the variable `key`.
+#[macro_export]
+macro_rules! trace {
+    (@field $key:ident) => {
+        &$key
+    };
+    (@field $key:ident = $value:expr) => {
+        &$value
+    };
+    ($level:ident, $event:literal $(, $key:ident $(= $value:expr)?)* $(,)?) => {
+        if $crate::trace::enabled($crate::trace::Level::$level, module_path!()) {
+            $crate::trace::emit(
+                $crate::trace::Level::$level,
+                module_path!(),
+                $event,
+                &[$((
+                    stringify!($key),
+                    $crate::trace!(@field $key $(= $value)?) as &dyn ::std::fmt::Debug,
+                )),*],
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_filters() {
+        let filters = ["7", "day-12::generate"]
+            .map(|filter| parse_filter(filter).unwrap())
+            .to_vec();
+
+        assert!(matches(&filters, "day_07"));
+        assert!(matches(&filters, "day_07::generate"));
+        assert!(matches(&filters, "day_12::generate"));
+        assert!(!matches(&filters, "day_12"));
+        assert!(!matches(&filters, "day_070"));
+        assert!(matches(&[], "day_01"));
+        assert!(parse_filter("day 7").is_err());
+    }
+
+    #[test]
+    fn test_disabled() {
+        // Tracing is never turned on in these tests
+        let mut evaluated = false;
+        crate::trace!(Trace, "skipped", value = evaluated = true);
+
+        assert!(!evaluated);
+    }
+}
@@ -0,0 +1,411 @@
+//! Watching simulations step by step.
+//!
+//! A simulation offers each of its frames to a [`Viewer`], which either
+//! plays them in the terminal ([`Player`]) or writes them to a file for
+//! golden tests ([`Recorder`]). Frames are drawn lazily, so a viewer that
+//! skips one never pays for rendering it.
+//!
+//! The player needs no dependencies: it draws with ANSI escape codes and
+//! reads single key presses after switching the terminal with `stty`.
+
+use crate::{ParseError, Part};
+use std::fmt;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::time::{Duration, Instant};
+
+/// Parses an input and runs the simulation of a part, offering every frame
+/// to the viewer.
+pub type Visualize = fn(&str, Part, &mut dyn Viewer) -> Result<(), ParseError>;
+
+/// Receives the frames of a simulation.
+pub trait Viewer {
+    /// Offers the next frame; `draw` is only called if it gets shown.
+    fn frame(&mut self, caption: fmt::Arguments, draw: &dyn Fn() -> String);
+
+    /// Shows the final state of the simulation, which is never skipped.
+    fn finish(&mut self, caption: fmt::Arguments, draw: &dyn Fn() -> String);
+}
+
+/// Writes every `every`th frame, and the final one, as plain text.
+///
+/// Each frame starts with a `--- N: caption` line, where `N` counts every
+/// frame offered, so a recording shows where in the run each one was.
+pub struct Recorder<W: Write> {
+    out: W,
+    every: usize,
+    offered: usize,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> Recorder<W> {
+    pub fn new(out: W, every: usize) -> Self {
+        Self {
+            out,
+            every: every.max(1),
+            offered: 0,
+            error: None,
+        }
+    }
+
+    fn write(&mut self, caption: fmt::Arguments, draw: &dyn Fn() -> String) {
+        if self.error.is_none() {
+            let result = writeln!(self.out, "--- {}: {caption}\n{}", self.offered, draw());
+            self.error = result.err();
+        }
+    }
+
+    /// The writer, or the first error writing to it.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        match self.error.take() {
+            Some(error) => Err(error),
+            None => self.out.flush().map(|()| self.out),
+        }
+    }
+}
+
+impl<W: Write> Viewer for Recorder<W> {
+    fn frame(&mut self, caption: fmt::Arguments, draw: &dyn Fn() -> String) {
+        self.offered += 1;
+        if (self.offered - 1).is_multiple_of(self.every) {
+            self.write(caption, draw);
+        }
+    }
+
+    fn finish(&mut self, caption: fmt::Arguments, draw: &dyn Fn() -> String) {
+        self.offered += 1;
+        self.write(caption, draw);
+    }
+}
+
+/// Compares `actual` with the golden file at `path`, or rewrites the file
+/// when `UPDATE_GOLDEN` is set in the environment.
+pub fn assert_golden(path: &Path, actual: &str) {
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(path, actual)
+            .unwrap_or_else(|error| panic!("cannot write {}: {error}", path.display()));
+        return;
+    }
+
+    let expected = std::fs::read_to_string(path).unwrap_or_else(|error| {
+        panic!(
+            "cannot read {}: {error}; run with UPDATE_GOLDEN=1 to create it",
+            path.display()
+        )
+    });
+
+    if let Some((i, (expected, actual))) = expected
+        .lines()
+        .zip(actual.lines())
+        .enumerate()
+        .find(|(_, (expected, actual))| expected != actual)
+    {
+        panic!(
+            "{} differs at line {}:\nexpected: {expected}\n  actual: {actual}\nrun with UPDATE_GOLDEN=1 to accept",
+            path.display(),
+            i + 1
+        );
+    }
+    assert_eq!(
+        expected.lines().count(),
+        actual.lines().count(),
+        "{} has a different number of lines; run with UPDATE_GOLDEN=1 to accept",
+        path.display()
+    );
+}
+
+/// A key press the player acts on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Key {
+    Pause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Key {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            b' ' | b'p' => Some(Key::Pause),
+            b'n' | b'.' => Some(Key::Step),
+            b'+' | b'=' => Some(Key::Faster),
+            b'-' | b'_' => Some(Key::Slower),
+            b'q' | 0x1b => Some(Key::Quit),
+            _ => None,
+        }
+    }
+}
+
+const MAX_FPS: u32 = 960;
+const MIN_FPS: u32 = 1;
+
+/// Draws at most this often, skipping frames beyond it.
+const REDRAW: Duration = Duration::from_millis(16);
+
+/// Plays frames in the terminal at a given speed.
+///
+/// Keys: space pauses and resumes, `n` steps one frame while paused, `+`
+/// and `-` double and halve the speed, `q` stops watching and lets the
+/// simulation finish on its own.
+pub struct Player {
+    fps: u32,
+    paused: bool,
+    quit: bool,
+    offered: usize,
+    keys: Option<Receiver<Key>>,
+    terminal: Option<RawTerminal>,
+    size: (usize, usize),
+    last_draw: Option<Instant>,
+    next_frame: Instant,
+}
+
+impl Player {
+    /// A player at `fps` frames per second, reading keys from stdin when it
+    /// is a terminal.
+    pub fn new(fps: u32) -> Self {
+        let terminal = RawTerminal::enter();
+        let keys = terminal.as_ref().map(|_| spawn_key_reader());
+
+        print!("\x1b[?25l");
+        Self {
+            fps: fps.clamp(MIN_FPS, MAX_FPS),
+            paused: false,
+            quit: false,
+            offered: 0,
+            keys,
+            size: terminal_size().unwrap_or((80, 24)),
+            terminal,
+            last_draw: None,
+            next_frame: Instant::now(),
+        }
+    }
+
+    fn handle(&mut self, key: Key) -> bool {
+        match key {
+            Key::Pause => self.paused = !self.paused,
+            Key::Faster => self.fps = (self.fps * 2).min(MAX_FPS),
+            Key::Slower => self.fps = (self.fps / 2).max(MIN_FPS),
+            Key::Quit => self.quit = true,
+            Key::Step => return self.paused,
+        }
+        false
+    }
+
+    /// Handles the keys pressed so far, and waits for one while paused.
+    /// Returns whether the user asked for a single step.
+    fn poll(&mut self, draw: &mut dyn FnMut(&Self)) -> bool {
+        loop {
+            let key = match &self.keys {
+                Some(keys) if self.paused => match keys.recv() {
+                    Ok(key) => key,
+                    // Nobody is left to unpause, so play on
+                    Err(_) => {
+                        self.paused = false;
+                        return false;
+                    }
+                },
+                Some(keys) => match keys.try_recv() {
+                    Ok(key) => key,
+                    Err(_) => return false,
+                },
+                None => return false,
+            };
+
+            let step = self.handle(key);
+            if step || self.quit {
+                return step;
+            }
+            if self.paused {
+                draw(self);
+            }
+        }
+    }
+
+    fn draw(&self, caption: &str, picture: &str) {
+        let (width, height) = self.size;
+        let mut screen = String::from("\x1b[H\x1b[2J");
+
+        // Keeps the top left of frames larger than the terminal
+        for line in picture.lines().take(height.saturating_sub(2)) {
+            screen.extend(line.chars().take(width));
+            screen.push_str("\r\n");
+        }
+
+        let state = if self.paused { "paused" } else { "playing" };
+        let status = format!(
+            "{}: {caption}  [{state}, {} fps]  space pause · n step · +/- speed · q quit",
+            self.offered, self.fps
+        );
+        screen.extend(status.chars().take(width));
+
+        let mut stdout = io::stdout().lock();
+        let _ = stdout.write_all(screen.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+impl Viewer for Player {
+    fn frame(&mut self, caption: fmt::Arguments, draw: &dyn Fn() -> String) {
+        self.offered += 1;
+        if self.quit {
+            return;
+        }
+
+        let caption = caption.to_string();
+        let picture = std::cell::OnceCell::new();
+        let mut show = |player: &Self| player.draw(&caption, picture.get_or_init(draw));
+
+        let stepped = self.poll(&mut show);
+        if self.quit {
+            return;
+        }
+
+        let now = Instant::now();
+        let due = self.last_draw.is_none_or(|last| now - last >= REDRAW);
+        if self.paused || stepped || due {
+            show(self);
+            self.last_draw = Some(now);
+        }
+
+        // Paused again after a step: wait for the next key before moving on
+        if stepped {
+            return;
+        }
+
+        self.next_frame += Duration::from_secs(1) / self.fps;
+        match self.next_frame.checked_duration_since(Instant::now()) {
+            Some(wait) => match &self.keys {
+                Some(keys) => {
+                    if let Ok(key) = keys.recv_timeout(wait) {
+                        self.handle(key);
+                    }
+                }
+                None => std::thread::sleep(wait),
+            },
+            // Too far behind, so stop trying to catch up
+            None => self.next_frame = Instant::now(),
+        }
+    }
+
+    fn finish(&mut self, caption: fmt::Arguments, draw: &dyn Fn() -> String) {
+        self.offered += 1;
+        self.paused = true;
+        self.draw(&format!("{caption} (done)"), &draw());
+
+        // Keeps the last frame on screen until the user quits
+        if let Some(keys) = &self.keys {
+            while keys.recv().is_ok_and(|key| key != Key::Quit) {}
+        }
+    }
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        print!("\x1b[?25h\r\n");
+        let _ = io::stdout().flush();
+        self.terminal.take();
+    }
+}
+
+/// Puts the terminal in non-canonical mode without echo, and restores the
+/// previous settings when dropped.
+struct RawTerminal {
+    saved: String,
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+impl RawTerminal {
+    fn enter() -> Option<Self> {
+        if !io::stdin().is_terminal() {
+            return None;
+        }
+
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "min", "1"])?;
+        Some(Self { saved })
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        stty(&[&self.saved]);
+    }
+}
+
+/// Columns and rows of the terminal, asking `stty`.
+fn terminal_size() -> Option<(usize, usize)> {
+    let size = stty(&["size"])?;
+    let (rows, columns) = size.split_once(' ')?;
+
+    match (columns.parse().ok()?, rows.parse().ok()?) {
+        // Pseudo terminals without a window report no size at all
+        (0, _) | (_, 0) => None,
+        size => Some(size),
+    }
+}
+
+/// Reads key presses on a thread of their own, since reading stdin blocks.
+fn spawn_key_reader() -> Receiver<Key> {
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::spawn(move || {
+        for byte in io::stdin().lock().bytes() {
+            let Ok(byte) = byte else { break };
+
+            if let Some(key) = Key::from_byte(byte) {
+                if sender.send(key).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    receiver
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn simulate(viewer: &mut dyn Viewer, steps: usize) {
+        for step in 1..=steps {
+            viewer.frame(format_args!("step {step}"), &|| "#".repeat(step));
+        }
+        viewer.finish(format_args!("done"), &|| "end".to_string());
+    }
+
+    #[test]
+    fn test_recorder() {
+        let mut recorder = Recorder::new(vec![], 2);
+        simulate(&mut recorder, 4);
+        let output = String::from_utf8(recorder.into_inner().unwrap()).unwrap();
+
+        assert_eq!(
+            "--- 1: step 1\n#\n--- 3: step 3\n###\n--- 5: done\nend\n",
+            output
+        );
+    }
+
+    #[test]
+    fn test_keys() {
+        assert_eq!(Some(Key::Pause), Key::from_byte(b' '));
+        assert_eq!(Some(Key::Faster), Key::from_byte(b'+'));
+        assert_eq!(None, Key::from_byte(b'x'));
+    }
+}
@@ -0,0 +1,40 @@
+//! Ragged calorie lists: elves carrying different numbers of snacks.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "elves",
+        default: 250,
+        range: 3..=1_000_000,
+        help: "elves, each one a paragraph",
+    },
+    Knob {
+        name: "items",
+        default: 15,
+        // Keeps the three largest totals within a `u32`
+        range: 1..=1_000,
+        help: "most snacks a single elf carries",
+    },
+    Knob {
+        name: "calories",
+        default: 60_000,
+        range: 1..=1_000_000,
+        help: "most calories in a single snack",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let elves = (0..knobs.get("elves"))
+        .map(|_| {
+            (0..=rng.below(knobs.get("items")))
+                .map(|_| format!("{}\n", 1 + rng.below(knobs.get("calories"))))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+
+    elves.join("\n")
+}
@@ -0,0 +1,90 @@
+//! Day 1: Calorie Counting.
+//!
+//! Parsing totals the calories each elf carries, largest first, so both
+//! parts only read the front of the list.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_01::Day01;
+//!
+//! let totals = Day01::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(24000, Day01::part1(&totals));
+//! assert_eq!(45000, Day01::part2(&totals));
+//! ```
+
+use aoc::parse::paragraphs;
+use aoc::{ParseError, Solution};
+
+pub mod generate;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut totals = paragraphs(input)
+            .map(|elf| {
+                elf.lines()
+                    .map(|line| line.token::<u32>(line.text, "calories"))
+                    .sum()
+            })
+            .collect::<Result<Vec<u32>, _>>()?;
+        if totals.len() < 3 {
+            // Points where the next elf would start
+            return Err(ParseError::at(
+                input.lines().count(),
+                1,
+                "",
+                format!("expected at least 3 elves, found {}", totals.len()),
+            ));
+        }
+        totals.sort_by(|a, b| b.cmp(a));
+        aoc::trace!(
+            Info,
+            "elves",
+            count = totals.len(),
+            top = &totals[..totals.len().min(3)]
+        );
+
+        Ok(totals)
+    }
+
+    fn part1(totals: &Self::Input) -> u32 {
+        totals[0]
+    }
+
+    fn part2(totals: &Self::Input) -> u32 {
+        totals[0..3].iter().sum::<u32>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_malformed_too_few_elves() {
+        let error = Day01::parse("1000\n2000\n\n3000\n").err().unwrap();
+
+        assert_eq!((5, 1), (error.line, error.column));
+        assert_eq!("expected at least 3 elves, found 2", error.message);
+        assert!(Day01::parse("").is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day01>(generate::GENERATOR, &[]);
+    }
+}
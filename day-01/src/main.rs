@@ -1,11 +1,5 @@
-fn main() {
-    let mut totals: Vec<u32> = include_str!("../input.txt")
-        .split("\n\n")
-        .map(|s| s.lines().map(|l| l.parse::<u32>().unwrap()).sum())
-        .collect();
-    totals.sort_by(|a, b| b.cmp(a));
-    let max_total = totals[0];
-    let n_totals_sum = totals[0..3].iter().sum::<u32>();
+use std::process::ExitCode;
 
-    println!("{max_total}\n{n_totals_sum}");
+fn main() -> ExitCode {
+    aoc::main::<day_01::Day01>(env!("CARGO_MANIFEST_DIR"))
 }
@@ -0,0 +1,25 @@
+//! Strategy guides: one round of rock paper scissors per line.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[Knob {
+    name: "rounds",
+    default: 2_500,
+    range: 1..=10_000_000,
+    help: "rounds in the strategy guide",
+}];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    (0..knobs.get("rounds"))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&['A', 'B', 'C']),
+                rng.pick(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
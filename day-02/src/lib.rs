@@ -0,0 +1,111 @@
+//! Day 2: Rock Paper Scissors.
+//!
+//! Each line of the strategy guide is scored on its own, reading the second
+//! column as a shape for the first part and as an outcome for the second.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_02::Day02;
+//!
+//! let rounds = Day02::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(15, Day02::part1(&rounds));
+//! assert_eq!(12, Day02::part2(&rounds));
+//! ```
+
+use aoc::parse::lines;
+use aoc::{ParseError, Solution};
+
+pub mod generate;
+
+/// Score of a round where the opponent plays `player1` and `player2` is
+/// the shape to play, `X` for rock, `Y` for paper and `Z` for scissors.
+/// Anything else scores nothing.
+///
+/// ```
+/// // Paper beats rock: 2 for paper and 6 for the win
+/// assert_eq!(8, day_02::eval_part1(&b'A', &b'Y'));
+/// ```
+pub fn eval_part1(player1: &u8, player2: &u8) -> u32 {
+    match (player1, player2) {
+        (b'A', b'X') => 4,
+        (b'A', b'Y') => 8,
+        (b'A', b'Z') => 3,
+        (b'B', b'X') => 1,
+        (b'B', b'Y') => 5,
+        (b'B', b'Z') => 9,
+        (b'C', b'X') => 7,
+        (b'C', b'Y') => 2,
+        (b'C', b'Z') => 6,
+        _ => 0,
+    }
+}
+
+/// Score of a round where the opponent plays `player1` and `player2` is
+/// the outcome to aim for, `X` to lose, `Y` to draw and `Z` to win.
+/// Anything else scores nothing.
+///
+/// ```
+/// // Drawing against rock: 1 for rock and 3 for the draw
+/// assert_eq!(4, day_02::eval_part2(&b'A', &b'Y'));
+/// ```
+pub fn eval_part2(player1: &u8, player2: &u8) -> u32 {
+    match (player1, player2) {
+        (b'A', b'X') => 3,
+        (b'A', b'Y') => 4,
+        (b'A', b'Z') => 8,
+        (b'B', b'X') => 1,
+        (b'B', b'Y') => 5,
+        (b'B', b'Z') => 9,
+        (b'C', b'X') => 2,
+        (b'C', b'Y') => 6,
+        (b'C', b'Z') => 7,
+        _ => 0,
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    type Input = Vec<(u8, u8)>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| match line.text.as_bytes() {
+                &[player1 @ b'A'..=b'C', b' ', player2 @ b'X'..=b'Z'] => Ok((player1, player2)),
+                _ => Err(line.error(
+                    1,
+                    format!("expected `<A|B|C> <X|Y|Z>`, found `{}`", line.text),
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(matches: &Self::Input) -> u32 {
+        matches.iter().map(|(p1, p2)| eval_part1(p1, p2)).sum()
+    }
+
+    fn part2(matches: &Self::Input) -> u32 {
+        matches.iter().map(|(p1, p2)| eval_part2(p1, p2)).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day02>(generate::GENERATOR, &[]);
+    }
+}
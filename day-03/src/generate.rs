@@ -0,0 +1,58 @@
+//! Rucksacks in groups of three, each with exactly one item in both
+//! compartments and each group with exactly one badge in common.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "groups",
+        default: 100,
+        range: 1..=1_000_000,
+        help: "groups of three elves",
+    },
+    Knob {
+        name: "items",
+        default: 16,
+        range: 2..=1_000,
+        help: "items in each compartment",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let items = knobs.get("items");
+    let mut rucksacks = String::new();
+
+    for _ in 0..knobs.get("groups") {
+        let mut types = ITEMS.to_vec();
+        rng.shuffle(&mut types);
+
+        // Each elf draws from its own 17 types so the badge is the only
+        // type the group shares
+        let (badge, pools) = types.split_first().unwrap();
+
+        for pool in pools.chunks(17) {
+            let (shared, rest) = pool.split_first().unwrap();
+            let (left_pool, right_pool) = rest.split_at(8);
+            let mut left = vec![*shared, *badge];
+            let mut right = vec![*shared];
+
+            left.extend((2..items).map(|_| *rng.pick(left_pool)));
+            right.extend((1..items).map(|_| *rng.pick(right_pool)));
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+
+            if rng.one_in(2) {
+                std::mem::swap(&mut left, &mut right);
+            }
+            rucksacks.extend(left.iter().chain(&right).map(|&item| char::from(item)));
+            rucksacks.push('\n');
+        }
+    }
+
+    rucksacks
+}
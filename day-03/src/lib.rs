@@ -0,0 +1,176 @@
+//! Day 3: Rucksack Reorganization.
+//!
+//! Finds the item shared by both compartments of each rucksack, and the
+//! badge shared by each group of three.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_03::Day03;
+//!
+//! let rucksacks = Day03::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(157, Day03::part1(&rucksacks));
+//! assert_eq!(70, Day03::part2(&rucksacks));
+//! ```
+
+use aoc::parse::lines;
+use aoc::{ParseError, Solution};
+
+pub mod generate;
+
+/// The first item of `a` that `b` holds too.
+///
+/// # Panics
+///
+/// If the two have no item in common.
+///
+/// ```
+/// assert_eq!(b'p', day_03::find_common(b"vJrwpWtwJgWr", b"hcsFMMfFFhFp"));
+/// ```
+pub fn find_common(a: &[u8], b: &[u8]) -> u8 {
+    a.iter()
+        .copied()
+        .find(|item| b.contains(item))
+        .expect("no common item")
+}
+
+/// Every item of `a` that `b` holds too, in the order of `a`.
+pub fn find_commons(a: &[u8], b: &[u8]) -> Vec<u8> {
+    a.iter().copied().filter(|item| b.contains(item)).collect()
+}
+
+/// Priority of an item: 1 to 26 for `a` to `z`, 27 to 52 for `A` to `Z`.
+///
+/// # Panics
+///
+/// If `ch` is not an ASCII letter.
+///
+/// ```
+/// assert_eq!(16, day_03::get_priority(b'p'));
+/// assert_eq!(38, day_03::get_priority(b'L'));
+/// ```
+pub fn get_priority(ch: u8) -> u32 {
+    (match ch {
+        b'a'..=b'z' => ch - b'a' + 1,
+        b'A'..=b'Z' => ch - b'A' + 27,
+        _ => unreachable!(),
+    }) as u32
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input = Vec<Vec<u8>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = lines(input).collect::<Vec<_>>();
+
+        for line in &lines {
+            if let Some(column) = line.text.find(|ch: char| !ch.is_ascii_alphabetic()) {
+                return Err(line.error(column + 1, "items must be ASCII letters"));
+            }
+            if line.text.len() % 2 == 1 {
+                return Err(line.error(
+                    line.text.len(),
+                    "odd number of items, compartments must hold as many",
+                ));
+            }
+            let (a, b) = line.text.as_bytes().split_at(line.text.len() / 2);
+            if find_commons(a, b).is_empty() {
+                return Err(line.error(1, "compartments have no item in common"));
+            }
+        }
+
+        if lines.len() % 3 != 0 {
+            // Points where the rest of the last group would start
+            return Err(ParseError::at(
+                lines.len(),
+                1,
+                "",
+                format!(
+                    "expected groups of 3 rucksacks, found {} left over",
+                    lines.len() % 3
+                ),
+            ));
+        }
+        for group in lines.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| group[i].text.as_bytes());
+            if find_commons(&find_commons(a, b), c).is_empty() {
+                return Err(group[0].error(1, "group of 3 has no badge in common"));
+            }
+        }
+
+        Ok(lines
+            .iter()
+            .map(|line| line.text.as_bytes().to_vec())
+            .collect())
+    }
+
+    fn part1(rucksacks: &Self::Input) -> u32 {
+        rucksacks
+            .iter()
+            .map(|l| {
+                let (a, b) = l.split_at(l.len() >> 1);
+                get_priority(find_common(a, b))
+            })
+            .sum()
+    }
+
+    fn part2(rucksacks: &Self::Input) -> u32 {
+        rucksacks
+            .chunks(3)
+            .map(|chunks| {
+                let [a, b, c]: &[_; 3] = chunks.try_into().unwrap();
+                get_priority(find_common(&find_commons(a, b), c))
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_malformed_rucksack() {
+        let odd = Day03::parse("abca\nabc\nabab\n").err().unwrap();
+        assert_eq!((2, 3), (odd.line, odd.column));
+        assert_eq!(
+            "odd number of items, compartments must hold as many",
+            odd.message
+        );
+
+        let distinct = Day03::parse("aa\nab\naa\n").err().unwrap();
+        assert_eq!((2, 1), (distinct.line, distinct.column));
+        assert_eq!("compartments have no item in common", distinct.message);
+    }
+
+    #[test]
+    fn test_malformed_group() {
+        let short = Day03::parse("aa\nbb\ncc\naa\n").err().unwrap();
+        assert_eq!((5, 1), (short.line, short.column));
+        assert_eq!(
+            "expected groups of 3 rucksacks, found 1 left over",
+            short.message
+        );
+
+        let no_badge = Day03::parse("aa\naa\nbb\n").err().unwrap();
+        assert_eq!((1, 1), (no_badge.line, no_badge.column));
+        assert_eq!("group of 3 has no badge in common", no_badge.message);
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day03>(generate::GENERATOR, &[]);
+    }
+}
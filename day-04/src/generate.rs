@@ -0,0 +1,36 @@
+//! Pairs of section assignments, some nested and some overlapping.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "pairs",
+        default: 1_000,
+        range: 1..=10_000_000,
+        help: "pairs of elves",
+    },
+    Knob {
+        name: "sections",
+        default: 99,
+        range: 1..=1_000_000_000,
+        help: "highest section ID",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let sections = knobs.get("sections") as i64;
+    let mut assignment = || {
+        let (a, b) = (rng.range(1..=sections), rng.range(1..=sections));
+        (a.min(b), a.max(b))
+    };
+
+    (0..knobs.get("pairs"))
+        .map(|_| {
+            let ((a_start, a_end), (b_start, b_end)) = (assignment(), assignment());
+            format!("{a_start}-{a_end},{b_start}-{b_end}\n")
+        })
+        .collect()
+}
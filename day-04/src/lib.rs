@@ -0,0 +1,92 @@
+//! Day 4: Camp Cleanup.
+//!
+//! Counts the pairs of elves whose section assignments contain or overlap
+//! one another.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_04::Day04;
+//!
+//! let pairs = Day04::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(2, Day04::part1(&pairs));
+//! assert_eq!(4, Day04::part2(&pairs));
+//! ```
+
+use aoc::parse::lines;
+use aoc::{ParseError, Solution};
+use std::ops::RangeInclusive;
+
+pub mod generate;
+
+/// Whether `a` covers every section of `b`.
+///
+/// ```
+/// assert!(day_04::range_contains(&(2..=8), &(3..=7)));
+/// assert!(!day_04::range_contains(&(3..=7), &(2..=8)));
+/// ```
+pub fn range_contains(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    a.start() <= b.start() && a.end() >= b.end()
+}
+
+/// Whether `a` and `b` share at least one section.
+///
+/// ```
+/// assert!(day_04::range_overlaps(&(5..=7), &(7..=9)));
+/// assert!(!day_04::range_overlaps(&(2..=4), &(6..=8)));
+/// ```
+pub fn range_overlaps(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    a.start() <= b.end() && a.end() >= b.start()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input = Vec<[RangeInclusive<u32>; 2]>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| {
+                let [a_start, a_end, b_start, b_end] = line.integers_n::<u32, 4>("sections")?;
+
+                Ok([a_start..=a_end, b_start..=b_end])
+            })
+            .collect()
+    }
+
+    fn part1(ranges: &Self::Input) -> usize {
+        ranges
+            .iter()
+            .filter(|[a_range, b_range]| {
+                range_contains(a_range, b_range) || range_contains(b_range, a_range)
+            })
+            .count()
+    }
+
+    fn part2(ranges: &Self::Input) -> usize {
+        ranges
+            .iter()
+            .filter(|[a_range, b_range]| range_overlaps(a_range, b_range))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day04>(generate::GENERATOR, &[]);
+    }
+}
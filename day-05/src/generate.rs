@@ -0,0 +1,93 @@
+//! A drawing of crate stacks and a procedure that only ever moves crates
+//! a stack actually holds.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "stacks",
+        default: 9,
+        // Stack labels are a single digit
+        range: 2..=9,
+        help: "stacks of crates",
+    },
+    Knob {
+        name: "height",
+        default: 8,
+        range: 1..=1_000,
+        help: "crates on the tallest stack",
+    },
+    Knob {
+        name: "moves",
+        default: 500,
+        range: 1..=1_000_000,
+        help: "steps of the rearrangement procedure",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let height = knobs.get("height");
+    let tallest = rng.below(knobs.get("stacks"));
+    let mut stacks = (0..knobs.get("stacks"))
+        .map(|i| {
+            let crates = if i == tallest {
+                height
+            } else {
+                rng.below(height + 1)
+            };
+            (0..crates)
+                .map(|_| char::from(b'A' + rng.below(26) as u8))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut input = drawing(&stacks);
+
+    input.push('\n');
+    for _ in 0..knobs.get("moves") {
+        // Crates only move between stacks, so some stack always has one
+        let sources = (0..stacks.len())
+            .filter(|&i| !stacks[i].is_empty())
+            .collect::<Vec<_>>();
+        let from = *rng.pick(&sources);
+        let to = (from + 1 + rng.below(stacks.len() - 1)) % stacks.len();
+        let quantity = 1 + rng.below(stacks[from].len());
+        let remaining = stacks[from].len() - quantity;
+        let moved = stacks[from].split_off(remaining);
+
+        stacks[to].extend(moved);
+        input += &format!("move {quantity} from {} to {}\n", from + 1, to + 1);
+    }
+
+    input
+}
+
+/// Stacks drawn from the top down, with their labels underneath.
+fn drawing(stacks: &[Vec<char>]) -> String {
+    let tallest = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut rows = (0..tallest)
+        .rev()
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(item) => format!("[{item}]"),
+                    None => "   ".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>();
+
+    rows.push(
+        (1..=stacks.len())
+            .map(|label| format!(" {label} "))
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    rows.iter()
+        .map(|row| format!("{}\n", row.trim_end()))
+        .collect()
+}
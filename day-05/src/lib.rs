@@ -0,0 +1,206 @@
+//! Day 5: Supply Stacks.
+//!
+//! Rearranges the stacks of crates with either crane and reads the crates
+//! left on top.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_05::Day05;
+//!
+//! let stacks = Day05::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!("CMZ", Day05::part1(&stacks));
+//! assert_eq!("MCD", Day05::part2(&stacks));
+//! ```
+
+use aoc::parse::{paragraphs, Line, Paragraph};
+use aoc::{ParseError, Solution};
+
+pub mod generate;
+
+/// Crates of each stack, from the bottom up.
+pub type Stacks = Vec<Vec<char>>;
+/// How many crates to move, and the stacks to move them from and to,
+/// numbered from 1.
+pub type Moves = Vec<(usize, usize, usize)>;
+
+fn get_message(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+/// Moves crates one at a time, which reverses each moved pile, and returns
+/// the crate on top of each stack.
+///
+/// ```
+/// let stacks = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+///
+/// assert_eq!("CMP", day_05::move_with_9000(stacks, &[(2, 2, 1)]));
+/// ```
+pub fn move_with_9000(mut stacks: Stacks, moves: &[(usize, usize, usize)]) -> String {
+    for &(quantity, from, to) in moves {
+        aoc::trace!(Debug, "move", quantity, from, to);
+        for _ in 0..quantity {
+            let item = stacks[from - 1].pop().expect("no items left");
+            stacks[to - 1].push(item);
+        }
+    }
+
+    get_message(&stacks)
+}
+
+/// Moves each pile of crates at once, keeping its order, and returns the
+/// crate on top of each stack.
+///
+/// ```
+/// let stacks = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+///
+/// assert_eq!("DMP", day_05::move_with_9001(stacks, &[(2, 2, 1)]));
+/// ```
+pub fn move_with_9001(mut stacks: Stacks, moves: &[(usize, usize, usize)]) -> String {
+    for &(quantity, from, to) in moves {
+        aoc::trace!(Debug, "move", quantity, from, to);
+        let new_length = stacks[from - 1].len() - quantity;
+        let mut items = stacks[from - 1].drain(new_length..).collect::<Vec<char>>();
+        stacks[to - 1].append(&mut items);
+    }
+
+    get_message(&stacks)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = (Stacks, Moves);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut sections = paragraphs(input);
+        let drawing = sections.next().unwrap_or(Paragraph { start: 0, text: "" });
+        let moves = sections.next().ok_or_else(|| {
+            let last = drawing.lines().last().unwrap_or(Line::new(0, ""));
+
+            last.error(1, "missing blank line between the stacks and the moves")
+        })?;
+
+        let mut rows = drawing.lines().collect::<Vec<_>>();
+        let labels = rows.pop().unwrap_or(Line::new(0, ""));
+        let mut count = 0;
+        for label in labels.text.split_whitespace() {
+            count += 1;
+            if labels.token::<usize>(label, "stack")? != count {
+                return Err(
+                    labels.error_at(label, format!("expected stack {count}, found `{label}`"))
+                );
+            }
+        }
+        if count == 0 {
+            return Err(labels.error(1, "expected the stack numbers under the stacks"));
+        }
+
+        let mut stacks = vec![vec![]; count];
+        for line in rows.iter().rev() {
+            for (i, column) in line.columns(4).enumerate() {
+                match (column.as_bytes().get(1), stacks.get_mut(i)) {
+                    (Some(&ch), Some(stack)) if ch.is_ascii_alphabetic() => stack.push(ch as char),
+                    (Some(&ch), None) if ch.is_ascii_alphabetic() => {
+                        return Err(
+                            line.error(4 * i + 2, format!("crate outside the {count} stacks"))
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Crates left on each stack, so that no move takes more than there are
+        let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+        let moves = moves
+            .lines()
+            .map(|line| {
+                let tokens = line.text.split_whitespace().collect::<Vec<_>>();
+                let ["move", quantity, "from", from, "to", to] = tokens[..] else {
+                    return Err(line.error(1, "expected `move <quantity> from <stack> to <stack>`"));
+                };
+                let number = |token: &str, max: usize| match line.token(token, "number")? {
+                    number @ 1.. if number <= max => Ok(number),
+                    _ => Err(line.error_at(token, format!("invalid number `{token}`"))),
+                };
+
+                let (count, from, to) = (
+                    number(quantity, usize::MAX)?,
+                    number(from, stacks.len())?,
+                    number(to, stacks.len())?,
+                );
+                if count > heights[from - 1] {
+                    return Err(line.error_at(
+                        quantity,
+                        format!(
+                            "cannot move {count} crates from stack {from}, which holds {}",
+                            heights[from - 1]
+                        ),
+                    ));
+                }
+                heights[from - 1] -= count;
+                heights[to - 1] += count;
+
+                Ok((count, from, to))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((stacks, moves))
+    }
+
+    fn part1((stacks, moves): &Self::Input) -> String {
+        move_with_9000(stacks.clone(), moves)
+    }
+
+    fn part2((stacks, moves): &Self::Input) -> String {
+        move_with_9001(stacks.clone(), moves)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day05>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_malformed_move() {
+        let input = include_str!("../examples/test-input.txt").replacen("move 3", "move 4", 1);
+        let error = Day05::parse(&input).err().unwrap();
+
+        assert_eq!((7, 6), (error.line, error.column));
+        assert_eq!(
+            "cannot move 4 crates from stack 1, which holds 3",
+            error.message
+        );
+    }
+
+    #[test]
+    fn test_malformed_drawing() {
+        let input = include_str!("../examples/test-input.txt");
+
+        let wide = input.replacen("[Z] [M] [P]", "[Z] [M] [P] [Q]", 1);
+        let error = Day05::parse(&wide).err().unwrap();
+        assert_eq!((3, 14), (error.line, error.column));
+        assert_eq!("crate outside the 3 stacks", error.message);
+
+        let skipped = input.replacen(" 1   2   3", " 1   3   2", 1);
+        let error = Day05::parse(&skipped).err().unwrap();
+        assert_eq!((4, 6), (error.line, error.column));
+        assert_eq!("expected stack 2, found `3`", error.message);
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day05>(generate::GENERATOR, &[]);
+    }
+}
@@ -0,0 +1,35 @@
+//! Datastream buffers over a small alphabet, so markers come late.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "length",
+        default: 4_096,
+        range: 14..=100_000_000,
+        help: "characters in the buffer",
+    },
+    Knob {
+        name: "letters",
+        default: 13,
+        range: 2..=26,
+        help: "distinct letters before the final marker",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let letters = knobs.get("letters");
+    let mut buffer = (0..knobs.get("length") - 14)
+        .map(|_| b'a' + rng.below(letters) as u8)
+        .collect::<Vec<_>>();
+    let mut marker = (b'a'..=b'z').collect::<Vec<_>>();
+
+    // Ends with 14 distinct letters so both markers exist
+    rng.shuffle(&mut marker);
+    buffer.extend(&marker[..14]);
+
+    String::from_utf8(buffer).unwrap() + "\n"
+}
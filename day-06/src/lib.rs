@@ -0,0 +1,137 @@
+//! Day 6: Tuning Trouble.
+//!
+//! Finds the start-of-packet and start-of-message markers in the signal.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_06::Day06;
+//!
+//! let signal = Day06::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(7, Day06::part1(&signal));
+//! assert_eq!(19, Day06::part2(&signal));
+//! ```
+
+use aoc::params::{Param, Params};
+use aoc::parse::{lines, Line};
+use aoc::{ParseError, Solution};
+
+pub mod generate;
+
+/// Number of characters read once the last `window_size` of them are all
+/// different, if that ever happens. Expects lowercase ASCII letters.
+///
+/// ```
+/// assert_eq!(Some(7), day_06::find_marker(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4));
+/// assert_eq!(None, day_06::find_marker(b"aaaa", 2));
+/// ```
+pub fn find_marker(char_bytes: &[u8], window_size: usize) -> Option<usize> {
+    let end = marker_end(char_bytes, window_size)?;
+    aoc::trace!(Info, "marker", window_size, end);
+
+    Some(end)
+}
+
+fn marker_end(char_bytes: &[u8], window_size: usize) -> Option<usize> {
+    char_bytes
+        .windows(window_size)
+        .position(|window| {
+            let mut checker = 0u32;
+
+            window.iter().all(|ch| {
+                let bit = 1 << (ch - b'a');
+                let unique = checker & bit == 0;
+                checker |= bit;
+                unique
+            })
+        })
+        .map(|i| i + window_size)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "packet_window",
+            default: 4,
+            range: 1..=26,
+            help: "different characters that start a packet",
+        },
+        Param {
+            name: "message_window",
+            default: 14,
+            range: 1..=26,
+            help: "different characters that start a message",
+        },
+    ];
+
+    type Input = (Vec<u8>, Params);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let line = lines(input).next().unwrap_or(Line::new(0, ""));
+        let signal = line.text.trim_end();
+
+        if let Some(column) = signal.find(|ch: char| !ch.is_ascii_lowercase()) {
+            return Err(line.error(column + 1, "signal must only contain lowercase letters"));
+        }
+        for (name, marker) in [
+            ("packet_window", "start-of-packet"),
+            ("message_window", "start-of-message"),
+        ] {
+            let window_size = params.get(name);
+            if marker_end(signal.as_bytes(), window_size).is_none() {
+                return Err(line.error(
+                    signal.len() + 1,
+                    format!("no {marker} marker of `{name}` = {window_size} different characters"),
+                ));
+            }
+        }
+
+        Ok((signal.as_bytes().to_vec(), params.clone()))
+    }
+
+    fn part1((char_bytes, params): &Self::Input) -> usize {
+        find_marker(char_bytes, params.get("packet_window")).expect("parse checks for the marker")
+    }
+
+    fn part2((char_bytes, params): &Self::Input) -> usize {
+        find_marker(char_bytes, params.get("message_window")).expect("parse checks for the marker")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_malformed_no_marker() {
+        let error = Day06::parse("abcdabcdabcdabcd\n").err().unwrap();
+
+        assert_eq!((1, 17), (error.line, error.column));
+        assert_eq!(
+            "no start-of-message marker of `message_window` = 14 different characters",
+            error.message
+        );
+        assert!(Day06::parse("aabbaabb\n").is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day06>(generate::GENERATOR, &[]);
+    }
+}
@@ -0,0 +1,109 @@
+//! Terminal transcripts of `cd` and `ls` through a deep directory tree.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "directories",
+        default: 200,
+        range: 1..=100_000,
+        help: "directories besides `/`",
+    },
+    Knob {
+        name: "depth",
+        default: 20,
+        // The parser recurses once per level
+        range: 1..=10_000,
+        help: "levels of the deepest directory",
+    },
+    Knob {
+        name: "files",
+        default: 600,
+        range: 1..=1_000_000,
+        help: "files across all directories",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+#[derive(Default)]
+struct Directory {
+    children: Vec<usize>,
+    files: Vec<u64>,
+}
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let depth = knobs.get("depth");
+    let count = knobs.get("directories").max(depth);
+    let mut tree = (0..=count)
+        .map(|_| Directory::default())
+        .collect::<Vec<_>>();
+    let mut levels = vec![0];
+
+    // A chain down to the deepest level, then the rest hung anywhere above it
+    for child in 1..=count {
+        let parent = if child <= depth {
+            child - 1
+        } else {
+            loop {
+                let parent = rng.below(child);
+                if levels[parent] < depth {
+                    break parent;
+                }
+            }
+        };
+
+        tree[parent].children.push(child);
+        levels.push(levels[parent] + 1);
+    }
+
+    // Between 40M and 70M in use, so part 2 has something to delete
+    let files = knobs.get("files");
+    let total = rng.range(45_000_000..=65_000_000) as u64;
+    let weights = (0..files)
+        .map(|_| rng.range(1..=1_000) as u64)
+        .collect::<Vec<_>>();
+    let sum = weights.iter().sum::<u64>();
+
+    for weight in weights {
+        let size = (total * weight / sum).max(1);
+        tree[rng.below(count + 1)].files.push(size);
+    }
+
+    let mut transcript = "$ cd /\n".to_string();
+    write_directory(&tree, 0, &mut transcript);
+    transcript
+}
+
+fn name(index: usize) -> String {
+    let mut name = String::new();
+    let mut index = index;
+
+    loop {
+        name.insert(0, char::from(b'a' + (index % 26) as u8));
+        index /= 26;
+        if index == 0 {
+            break name;
+        }
+        index -= 1;
+    }
+}
+
+fn write_directory(tree: &[Directory], index: usize, transcript: &mut String) {
+    let directory = &tree[index];
+
+    transcript.push_str("$ ls\n");
+    for &child in &directory.children {
+        transcript.push_str(&format!("dir {}\n", name(child)));
+    }
+    for (i, size) in directory.files.iter().enumerate() {
+        transcript.push_str(&format!("{size} {}.dat\n", name(i)));
+    }
+
+    for &child in &directory.children {
+        transcript.push_str(&format!("$ cd {}\n", name(child)));
+        write_directory(tree, child, transcript);
+        transcript.push_str("$ cd ..\n");
+    }
+}
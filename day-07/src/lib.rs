@@ -0,0 +1,307 @@
+//! Day 7: No Space Left On Device.
+//!
+//! Rebuilds the file system from the terminal output, then looks for small
+//! directories and for the smallest one worth deleting.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_07::Day07;
+//!
+//! let root = Day07::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(95437, Day07::part1(&root));
+//! assert_eq!(24933642, Day07::part2(&root));
+//! ```
+
+use aoc::params::{Param, Params};
+use aoc::parse::{lines, Line};
+use aoc::{ParseError, Solution};
+use serde::Serialize;
+use std::cell::RefCell;
+
+pub mod generate;
+
+/// A directory rebuilt from the terminal output, with the size of the
+/// files directly inside it.
+#[derive(Clone, Default)]
+pub struct Directory {
+    name: String,
+    directories: RefCell<Vec<Directory>>,
+    size: u64,
+}
+
+impl Directory {
+    fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// Size of every file inside, however deep.
+    pub fn total_size(&self) -> u64 {
+        let dir_sizes = self
+            .directories
+            .borrow()
+            .iter()
+            .map(Self::total_size)
+            .sum::<u64>();
+        self.size + dir_sizes
+    }
+}
+
+fn read_dir<'a>(
+    parent_dir: &mut Directory,
+    lines: &mut impl Iterator<Item = Line<'a>>,
+) -> Result<(), ParseError> {
+    while let Some(line) = lines.next() {
+        let tokens = line.text.split_whitespace().collect::<Vec<_>>();
+
+        match tokens[..] {
+            ["$", "cd", dir_name] => {
+                if dir_name == ".." {
+                    aoc::trace!(
+                        Debug,
+                        "cd ..",
+                        line = line.index + 1,
+                        size = parent_dir.size
+                    );
+                    return Ok(());
+                }
+                aoc::trace!(Debug, "cd", line = line.index + 1, dir = dir_name);
+                let mut child_dir = Directory {
+                    name: dir_name.to_string(),
+                    ..Directory::new()
+                };
+                read_dir(&mut child_dir, lines)?;
+                parent_dir.directories.borrow_mut().push(child_dir)
+            }
+            ["$", "ls"] => {}
+            ["dir", _] => {}
+            [file_size, file_name] => {
+                let size: u64 = line.token(file_size, "size")?;
+                aoc::trace!(
+                    Trace,
+                    "file",
+                    line = line.index + 1,
+                    name = file_name,
+                    size = size
+                );
+
+                parent_dir.size += size;
+            }
+            _ => return Err(line.error(1, "unexpected line")),
+        }
+    }
+
+    Ok(())
+}
+
+/// Total size of `parent_dir`, then of every directory inside it, depth
+/// first.
+///
+/// ```
+/// use aoc::Solution;
+///
+/// let input = "$ cd /\n$ ls\ndir a\n10 b.txt\n$ cd a\n$ ls\n5 c.txt\n";
+/// let (root, _) = day_07::Day07::parse(input).unwrap();
+///
+/// assert_eq!(vec![15, 5], day_07::get_dir_sizes(&root));
+/// ```
+pub fn get_dir_sizes(parent_dir: &Directory) -> Vec<u64> {
+    let mut sizes = vec![parent_dir.total_size()];
+
+    for child_dir in parent_dir.directories.borrow().iter() {
+        sizes.append(&mut get_dir_sizes(child_dir));
+    }
+
+    sizes
+}
+
+/// Path of `parent_dir`, then of every directory inside it, in the order
+/// of [`get_dir_sizes`].
+fn get_dir_paths(parent_dir: &Directory, path: &str) -> Vec<String> {
+    let mut paths = vec![path.to_string()];
+
+    for child_dir in parent_dir.directories.borrow().iter() {
+        let child_path = format!("{}/{}", path.trim_end_matches('/'), child_dir.name);
+        paths.append(&mut get_dir_paths(child_dir, &child_path));
+    }
+
+    paths
+}
+
+/// A directory as shown by `--explain`.
+#[derive(Serialize)]
+struct Explained {
+    path: String,
+    size: u64,
+}
+
+fn part1(parent_dir: &Directory, small_size: u64) -> u64 {
+    let sizes = get_dir_sizes(parent_dir);
+    aoc::explain::note("summed_directories", || {
+        get_dir_paths(parent_dir, "/")
+            .into_iter()
+            .zip(&sizes)
+            .filter(|&(_, &size)| size <= small_size)
+            .map(|(path, &size)| Explained { path, size })
+            .collect::<Vec<_>>()
+    });
+
+    sizes
+        .iter()
+        .filter(|&&size| size <= small_size)
+        .sum::<u64>()
+}
+
+/// Space to free for the update, given the total size of the root.
+fn space_to_free(used_space: u64, disk_size: u64, needed_space: u64) -> u64 {
+    needed_space.saturating_sub(disk_size.saturating_sub(used_space))
+}
+
+fn part2(parent_dir: &Directory, disk_size: u64, needed_space: u64) -> u64 {
+    let sizes = get_dir_sizes(parent_dir);
+    let unused_space = disk_size.saturating_sub(sizes[0]);
+    let min_space = space_to_free(sizes[0], disk_size, needed_space);
+    aoc::trace!(
+        Info,
+        "space",
+        used = sizes[0],
+        unused = unused_space,
+        to_free = min_space
+    );
+
+    let (deleted, &size) = sizes
+        .iter()
+        .enumerate()
+        .filter(|&(_, &size)| size >= min_space)
+        .min_by_key(|&(_, &size)| size)
+        .expect("parse checks that deleting the root frees enough space");
+    aoc::explain::note("space_to_free", || min_space);
+    aoc::explain::note("deleted", || Explained {
+        path: get_dir_paths(parent_dir, "/").swap_remove(deleted),
+        size,
+    });
+
+    size
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "small_size",
+            default: 100_000,
+            range: 0..=i64::MAX,
+            help: "largest total size of the directories to add up",
+        },
+        Param {
+            name: "disk_size",
+            default: 70_000_000,
+            range: 0..=i64::MAX,
+            help: "size of the file system",
+        },
+        Param {
+            name: "needed_space",
+            default: 30_000_000,
+            range: 0..=i64::MAX,
+            help: "unused space the update needs",
+        },
+    ];
+
+    type Input = (Directory, Params);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let mut lines = lines(input);
+        let first = lines.next().unwrap_or(Line { index: 0, text: "" });
+        if first.text != "$ cd /" {
+            return Err(first.error(1, "expected `$ cd /`"));
+        }
+        let mut parent_dir = Directory::new();
+
+        read_dir(&mut parent_dir, &mut lines)?;
+
+        // The root is the largest directory, so if deleting it is not enough
+        // then nothing is
+        let used_space = parent_dir.total_size();
+        let needed_space = params.get("needed_space");
+        if space_to_free(used_space, params.get("disk_size"), needed_space) > used_space {
+            return Err(first.error(
+                1,
+                format!("no directory frees `needed_space` = {needed_space}"),
+            ));
+        }
+        Ok((parent_dir, params.clone()))
+    }
+
+    fn part1((parent_dir, params): &Self::Input) -> u64 {
+        part1(parent_dir, params.get("small_size"))
+    }
+
+    fn part2((parent_dir, params): &Self::Input) -> u64 {
+        part2(
+            parent_dir,
+            params.get("disk_size"),
+            params.get("needed_space"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day07>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_malformed_first_line() {
+        for input in ["", "$ ls\n", "$ cd a\n$ ls\n"] {
+            let error = Day07::parse(input).err().unwrap();
+
+            assert_eq!((1, 1), (error.line, error.column));
+            assert_eq!("expected `$ cd /`", error.message);
+        }
+    }
+
+    #[test]
+    fn test_malformed_needed_space() {
+        let input = include_str!("../examples/test-input.txt");
+        let params = Params::new(Day07::PARAMS, &[("needed_space", 80_000_000)]).unwrap();
+        let error = Day07::parse_with(input, &params).err().unwrap();
+
+        assert_eq!((1, 1), (error.line, error.column));
+        assert_eq!(
+            "no directory frees `needed_space` = 80000000",
+            error.message
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day07>(generate::GENERATOR, &[]);
+    }
+
+    #[test]
+    fn test_explain() {
+        let input = Day07::parse(include_str!("../examples/test-input.txt")).unwrap();
+        let (_, explanation) = aoc::explain::explain(|| Day07::part2(&input));
+
+        assert_eq!(
+            "space to free: 8381165\ndeleted: path=/d size=24933642\n",
+            explanation.to_string()
+        );
+    }
+}
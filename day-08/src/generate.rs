@@ -0,0 +1,39 @@
+//! Forests of tree heights, mostly short so lines of sight run long.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "width",
+        default: 99,
+        range: 1..=10_000,
+        help: "trees in each row",
+    },
+    Knob {
+        name: "height",
+        default: 99,
+        range: 1..=10_000,
+        help: "rows of trees",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    (0..knobs.get("height"))
+        .map(|_| {
+            let row = (0..knobs.get("width"))
+                .map(|_| {
+                    let height = if rng.one_in(3) {
+                        rng.below(10)
+                    } else {
+                        rng.below(4)
+                    };
+                    char::from(b'0' + height as u8)
+                })
+                .collect::<String>();
+            row + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,137 @@
+//! Day 8: Treetop Tree House.
+//!
+//! Counts the trees visible from outside the grid and finds the best
+//! scenic score.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_08::Day08;
+//!
+//! let grid = Day08::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(21, Day08::part1(&grid));
+//! assert_eq!(8, Day08::part2(&grid));
+//! ```
+
+use aoc::{ParseError, Solution};
+use grid::{Grid, Pos, ORTHOGONAL};
+
+pub mod generate;
+pub mod reference;
+
+/// Whether a tree of `height` at `pos` can be seen from outside the grid.
+///
+/// ```
+/// use aoc::Solution;
+/// use day_08::Day08;
+///
+/// let grid = Day08::parse(include_str!("../examples/test-input.txt")).unwrap();
+///
+/// assert!(day_08::is_visible(&grid, (1, 1), grid[(1, 1)]));
+/// assert!(!day_08::is_visible(&grid, (3, 1), grid[(3, 1)]));
+/// ```
+pub fn is_visible(grid: &Grid<u8>, pos: Pos, height: u8) -> bool {
+    // Is taller than every tree between it and an edge
+    ORTHOGONAL
+        .iter()
+        .any(|&offset| grid.ray(pos, offset).all(|(_, &other)| height > other))
+}
+
+fn part1(grid: &Grid<u8>) -> usize {
+    grid.iter()
+        .filter(|&(pos, &height)| is_visible(grid, pos, height))
+        .count()
+}
+
+/// Product of how many trees a tree of `height` at `pos` sees in each
+/// direction, which is 0 on the edge.
+///
+/// ```
+/// use aoc::Solution;
+/// use day_08::Day08;
+///
+/// let grid = Day08::parse(include_str!("../examples/test-input.txt")).unwrap();
+///
+/// assert_eq!(8, day_08::calculate_scenic_score(&grid, (2, 3), grid[(2, 3)]));
+/// ```
+pub fn calculate_scenic_score(grid: &Grid<u8>, pos: Pos, height: u8) -> usize {
+    ORTHOGONAL
+        .iter()
+        .map(|&offset| {
+            // Trees seen up to and including the first one at least as tall
+            let mut distance = 0;
+            for (_, &other) in grid.ray(pos, offset) {
+                distance += 1;
+                if other >= height {
+                    break;
+                }
+            }
+            distance
+        })
+        .product()
+}
+
+fn part2(grid: &Grid<u8>) -> usize {
+    grid.iter()
+        .map(|(pos, &height)| calculate_scenic_score(grid, pos, height))
+        .max()
+        .expect("cannot get highest scenic score")
+}
+
+fn build_grid(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |b| match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        _ => Err("tree heights must be digits".to_string()),
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build_grid(input)
+    }
+
+    fn part1(grid: &Self::Input) -> usize {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> usize {
+        part2(grid)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::generate::Knobs;
+    use aoc::rng::Rng;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day08>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day08>(generate::GENERATOR, &[]);
+    }
+
+    #[test]
+    fn test_reference() {
+        let small = |rng: &mut Rng| {
+            let knobs = [("width", 1 + rng.below(8)), ("height", 1 + rng.below(8))];
+            generate::generate(rng, &Knobs::new(generate::KNOBS, &knobs).unwrap())
+        };
+        let naive = |input: &str| (reference::part1(input), reference::part2(input));
+
+        aoc::reference::check::<Day08, _, _>(&[], small, naive);
+    }
+}
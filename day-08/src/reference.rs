@@ -0,0 +1,71 @@
+//! Naive solver to check the optimized one against.
+//!
+//! Parses the grid by hand and walks from every tree to the edges with
+//! plain index loops, sharing no code with the [`grid`] based solution.
+
+fn parse(input: &str) -> Vec<Vec<u8>> {
+    input
+        .lines()
+        .map(|line| line.bytes().map(|b| b - b'0').collect())
+        .collect()
+}
+
+/// Heights seen walking from `(x, y)` towards each edge, nearest first.
+fn lines_of_sight(trees: &[Vec<u8>], x: usize, y: usize) -> [Vec<u8>; 4] {
+    let row = &trees[y];
+    let column = trees.iter().map(|row| row[x]).collect::<Vec<_>>();
+
+    [
+        column[..y].iter().rev().copied().collect(),
+        column[y + 1..].to_vec(),
+        row[..x].iter().rev().copied().collect(),
+        row[x + 1..].to_vec(),
+    ]
+}
+
+pub fn part1(input: &str) -> usize {
+    let trees = parse(input);
+    let mut visible = 0;
+
+    for y in 0..trees.len() {
+        for x in 0..trees[y].len() {
+            let height = trees[y][x];
+
+            if lines_of_sight(&trees, x, y)
+                .iter()
+                .any(|line| line.iter().all(|&other| other < height))
+            {
+                visible += 1;
+            }
+        }
+    }
+
+    visible
+}
+
+pub fn part2(input: &str) -> usize {
+    let trees = parse(input);
+    let mut best = 0;
+
+    for y in 0..trees.len() {
+        for x in 0..trees[y].len() {
+            let height = trees[y][x];
+            let mut score = 1;
+
+            for line in lines_of_sight(&trees, x, y) {
+                let mut seen = 0;
+                for other in line {
+                    seen += 1;
+                    if other >= height {
+                        break;
+                    }
+                }
+                score *= seen;
+            }
+
+            best = best.max(score);
+        }
+    }
+
+    best
+}
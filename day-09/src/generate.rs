@@ -0,0 +1,31 @@
+//! Long lists of head motions for the rope.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "moves",
+        default: 2_000,
+        range: 1..=10_000_000,
+        help: "motions of the head",
+    },
+    Knob {
+        name: "steps",
+        default: 20,
+        // Steps are parsed as a `u8`
+        range: 1..=255,
+        help: "most steps in a single motion",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    (0..knobs.get("moves"))
+        .map(|_| {
+            let direction = rng.pick(&['U', 'D', 'L', 'R']);
+            format!("{direction} {}\n", 1 + rng.below(knobs.get("steps")))
+        })
+        .collect()
+}
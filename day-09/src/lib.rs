@@ -0,0 +1,187 @@
+//! Day 9: Rope Bridge.
+//!
+//! Pulls a rope of 2 or 10 knots after its head and counts the positions
+//! its tail visits.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_09::Day09;
+//!
+//! let rope = Day09::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(13, Day09::part1(&rope));
+//! assert_eq!(1, Day09::part2(&rope));
+//! ```
+
+use aoc::parse::{lines, Line};
+use aoc::{checkpoint, ParseError, Solution};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub mod generate;
+pub mod visual;
+
+#[derive(Clone)]
+pub struct Move(i32, i32, u8);
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(0, line);
+        let (direction, steps) = line
+            .text
+            .split_once(' ')
+            .ok_or_else(|| line.error(1, "expected `<direction> <steps>`"))?;
+        let steps = line.token(steps, "steps")?;
+
+        Ok(match direction {
+            "U" => Self(0, 1, steps),
+            "L" => Self(-1, 0, steps),
+            "R" => Self(1, 0, steps),
+            "D" => Self(0, -1, steps),
+            _ => return Err(line.error(1, format!("unknown direction `{direction}`"))),
+        })
+    }
+}
+
+/// Position of a knot, with `y` growing upwards.
+pub type Pos = (i32, i32);
+
+/// The motions of the head, and the knots as the last simulation left them.
+#[derive(Clone, Default)]
+pub struct Rope {
+    knots: Vec<Pos>,
+    moves: Vec<Move>,
+}
+
+impl Rope {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            moves: lines(input)
+                .map(|line| line.parse::<Move>())
+                .collect::<Result<_, _>>()?,
+            ..Default::default()
+        })
+    }
+
+    fn simulate(&mut self, rope_size: usize) -> usize {
+        self.simulate_with(rope_size, |_, _| {}).len()
+    }
+
+    /// Positions the tail visits, calling `on_step` with the knots and the
+    /// positions visited so far after every single step of the head.
+    ///
+    /// Snapshots hold the number of moves done, the knots and the visited
+    /// positions.
+    ///
+    /// ```
+    /// use aoc::Solution;
+    ///
+    /// let mut rope = day_09::Day09::parse("R 2\nU 2\n").unwrap();
+    /// let mut steps = 0;
+    /// let visited = rope.simulate_with(2, |_, _| steps += 1);
+    ///
+    /// assert_eq!(4, steps);
+    /// assert_eq!(3, visited.len());
+    /// ```
+    pub fn simulate_with(
+        &mut self,
+        rope_size: usize,
+        mut on_step: impl FnMut(&[Pos], &HashSet<Pos>),
+    ) -> HashSet<Pos> {
+        let (done, visited) = match checkpoint::resume::<(usize, Vec<Pos>, HashSet<Pos>)>() {
+            Some((done, knots, visited)) => {
+                self.knots = knots;
+                (done, visited)
+            }
+            None => {
+                self.knots = vec![(0, 0); rope_size];
+                (0, HashSet::new())
+            }
+        };
+
+        self.moves.iter().enumerate().skip(done).fold(
+            visited,
+            |mut visited: HashSet<Pos>, (i, &Move(x, y, steps))| {
+                aoc::trace!(Debug, "move", direction = (x, y), steps);
+                for _ in 0..steps {
+                    self.knots[0] = (self.knots[0].0 + x, self.knots[0].1 + y);
+
+                    for i in 1..rope_size {
+                        let (head, tail) = (self.knots[i - 1], self.knots[i]);
+                        let (dx, dy) = (head.0 - tail.0, head.1 - tail.1);
+
+                        if dx.abs() > 1 || dy.abs() > 1 {
+                            self.knots[i] = (tail.0 + dx.signum(), tail.1 + dy.signum())
+                        }
+                    }
+
+                    // Tail of the rope is the last of the knots
+                    visited.insert(self.knots[rope_size - 1]);
+                    aoc::trace!(
+                        Trace,
+                        "step",
+                        head = self.knots[0],
+                        tail = self.knots[rope_size - 1]
+                    );
+                    on_step(&self.knots, &visited);
+                }
+                checkpoint::save(|| (i + 1, &self.knots, &visited));
+
+                visited
+            },
+        )
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input = Rope;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Rope::new(input)
+    }
+
+    fn part1(rope: &Self::Input) -> usize {
+        rope.clone().simulate(2)
+    }
+
+    fn part2(rope: &Self::Input) -> usize {
+        rope.clone().simulate(10)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day09>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day09>(generate::GENERATOR, &[("moves", 200)]);
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        aoc::checkpoint::check::<Day09>(include_str!("../examples/test-input.txt"));
+    }
+
+    #[test]
+    fn test_unknown_direction() {
+        let error = Rope::new("R 4\nX 3").err().unwrap();
+
+        assert_eq!((2, 1), (error.line, error.column));
+        assert_eq!("unknown direction `X`", error.message);
+    }
+}
@@ -0,0 +1,81 @@
+//! The rope moving step by step, in a window that follows the head.
+
+use crate::{Day09, Pos};
+use aoc::visual::Viewer;
+use aoc::{ParseError, Part, Solution};
+use std::collections::HashSet;
+
+const WIDTH: i32 = 41;
+const HEIGHT: i32 = 21;
+
+/// The window around `center`, with the head as `H`, the other knots by
+/// number (`T` when there is only a tail), visited cells as `#` and the
+/// start as `s`. Knots closer to the head hide those behind them.
+fn draw(center: Pos, knots: &[Pos], visited: &HashSet<Pos>) -> String {
+    let (left, top) = (center.0 - WIDTH / 2, center.1 + HEIGHT / 2);
+
+    (0..HEIGHT)
+        .map(|row| {
+            (0..WIDTH)
+                .map(|column| {
+                    let cell = (left + column, top - row);
+
+                    match knots.iter().position(|&knot| knot == cell) {
+                        Some(0) => 'H',
+                        Some(_) if knots.len() == 2 => 'T',
+                        Some(i) => char::from_digit(i as u32, 36).unwrap_or('*'),
+                        None if cell == (0, 0) => 's',
+                        None if visited.contains(&cell) => '#',
+                        None => '.',
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Offers a frame after every step of the head of the rope for `part`.
+pub fn visualize(input: &str, part: Part, viewer: &mut dyn Viewer) -> Result<(), ParseError> {
+    let mut rope = Day09::parse(input)?;
+    let rope_size = match part {
+        Part::One => 2,
+        Part::Two => 10,
+    };
+
+    let mut steps = 0;
+    let visited = rope.simulate_with(rope_size, |knots, visited| {
+        steps += 1;
+        viewer.frame(
+            format_args!("step {steps}, tail visited {}", visited.len()),
+            &|| draw(knots[0], knots, visited),
+        );
+    });
+
+    let knots = &rope.knots;
+    viewer.finish(
+        format_args!("step {steps}, tail visited {}", visited.len()),
+        &|| draw(knots[0], knots, &visited),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::visual::{assert_golden, Recorder};
+    use std::path::Path;
+
+    #[test]
+    fn test_golden() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let input = std::fs::read_to_string(dir.join("examples/test-input-larger.txt")).unwrap();
+
+        let mut recorder = Recorder::new(vec![], 8);
+        visualize(&input, Part::Two, &mut recorder).unwrap();
+        let frames = String::from_utf8(recorder.into_inner().unwrap()).unwrap();
+
+        assert_golden(&dir.join("golden/test-input-larger.frames"), &frames);
+    }
+}
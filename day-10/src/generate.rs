@@ -0,0 +1,32 @@
+//! Programs of `noop` and `addx` that keep the sprite mostly on screen.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[Knob {
+    name: "instructions",
+    default: 146,
+    // Keeps the sum of signal strengths within an `i32`
+    range: 1..=20_000,
+    help: "instructions in the program",
+}];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let mut x = 1;
+
+    (0..knobs.get("instructions"))
+        .map(|_| {
+            if rng.one_in(3) {
+                return "noop\n".to_string();
+            }
+
+            // Drifts back towards the middle of the 40 pixel wide screen
+            let target = rng.range(-5..=44);
+            let value = (target - x).clamp(-15, 15);
+            x += value;
+            format!("addx {value}\n")
+        })
+        .collect()
+}
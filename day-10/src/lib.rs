@@ -0,0 +1,151 @@
+//! Day 10: Cathode-Ray Tube.
+//!
+//! Runs the program of the handheld device, adding up signal strengths and
+//! drawing the letters its CRT shows.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_10::Day10;
+//!
+//! let program = Day10::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(13140, Day10::part1(&program));
+//! assert!(Day10::part2(&program).starts_with("##..##..##..##.."));
+//! ```
+
+use aoc::parse::{lines, Line};
+use aoc::{ParseError, Solution};
+use grid::Grid;
+use std::str::FromStr;
+
+pub mod generate;
+pub mod visual;
+
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let line = Line::new(0, line);
+
+        match line.text.split_once(' ') {
+            None if line.text == "noop" => Ok(Instruction::Noop),
+            Some(("addx", v)) => line.token(v, "value").map(Instruction::Addx),
+            _ => Err(line.error(1, format!("unknown instruction `{}`", line.text))),
+        }
+    }
+}
+
+fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    lines(input).map(|line| line.parse()).collect()
+}
+
+/// Runs `program`, calling `during` with the number of every cycle,
+/// starting at 1, and the value of the X register during it.
+///
+/// ```
+/// use day_10::{execute, Instruction};
+///
+/// let mut cycles = vec![];
+/// execute(&[Instruction::Noop, Instruction::Addx(3)], |cycle, x| {
+///     cycles.push((cycle, x))
+/// });
+///
+/// assert_eq!(vec![(1, 1), (2, 1), (3, 1)], cycles);
+/// ```
+pub fn execute(program: &[Instruction], mut during: impl FnMut(i32, i32)) {
+    let (mut cycle, mut x) = (0, 1);
+
+    for instruction in program {
+        aoc::trace!(Trace, "execute", cycle = cycle + 1, x, instruction);
+        cycle += 1;
+        during(cycle, x);
+
+        if let Instruction::Addx(v) = instruction {
+            cycle += 1;
+            during(cycle, x);
+
+            x += v;
+        }
+    }
+}
+
+fn part1(program: &[Instruction]) -> i32 {
+    let mut signal = 0;
+
+    execute(program, |cycle, x| {
+        if (cycle - 20) % 40 == 0 {
+            aoc::trace!(Debug, "signal", cycle, x, strength = cycle * x);
+            signal += cycle * x
+        }
+    });
+
+    signal
+}
+
+/// Lights the pixel the CRT draws during `cycle` if the sprite covers it.
+fn draw_pixel(cycle: i32, x: i32, screen: &mut Grid<bool>) {
+    let pixel = cycle as usize - 1;
+    let (column, row) = (pixel % SCREEN_WIDTH, pixel / SCREEN_WIDTH);
+
+    if let Some(pixel) = screen.get_mut((column, row)) {
+        *pixel = (x - column as i32).abs() < 2;
+    }
+}
+
+fn render(screen: &Grid<bool>) -> String {
+    screen.render(|&lit| if lit { '#' } else { '.' })
+}
+
+fn part2(program: &[Instruction]) -> String {
+    let mut screen = Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, false);
+
+    execute(program, |cycle, x| draw_pixel(cycle, x, &mut screen));
+    render(&screen)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Input = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_program(input)
+    }
+
+    fn part1(program: &Self::Input) -> i32 {
+        part1(program)
+    }
+
+    fn part2(program: &Self::Input) -> String {
+        part2(program)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day10>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day10>(generate::GENERATOR, &[]);
+    }
+}
@@ -0,0 +1,73 @@
+//! The CRT drawing its picture one pixel per cycle, with the sprite below.
+
+use crate::{draw_pixel, execute, Day10, SCREEN_HEIGHT, SCREEN_WIDTH};
+use aoc::visual::Viewer;
+use aoc::{ParseError, Part, Solution};
+use grid::Grid;
+
+/// The screen with the pixels after the first `drawn` left blank, then the
+/// row of the sprite centered on `x`.
+fn draw(screen: &Grid<bool>, drawn: usize, x: i32) -> String {
+    let mut pixel = 0;
+    let screen = screen.render(|&lit| {
+        pixel += 1;
+        match lit {
+            _ if pixel > drawn => ' ',
+            true => '#',
+            false => '.',
+        }
+    });
+    let sprite = (0..SCREEN_WIDTH as i32)
+        .map(|column| if (x - column).abs() < 2 { '#' } else { '.' })
+        .collect::<String>();
+
+    format!("{screen}\n\n{sprite}")
+}
+
+/// Offers a frame after every cycle, captioned with the signal strength
+/// so far. Both parts run the same program, so `_part` changes nothing.
+pub fn visualize(input: &str, _part: Part, viewer: &mut dyn Viewer) -> Result<(), ParseError> {
+    let program = Day10::parse(input)?;
+    let mut screen = Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, false);
+    let (mut last, mut signal) = ((0, 1), 0);
+
+    execute(&program, |cycle, x| {
+        draw_pixel(cycle, x, &mut screen);
+        if (cycle - 20) % 40 == 0 {
+            signal += cycle * x;
+        }
+
+        last = (cycle, x);
+        viewer.frame(
+            format_args!("cycle {cycle}, X = {x}, signal {signal}"),
+            &|| draw(&screen, cycle as usize, x),
+        );
+    });
+
+    let (cycle, x) = last;
+    viewer.finish(
+        format_args!("cycle {cycle}, X = {x}, signal {signal}"),
+        &|| draw(&screen, cycle as usize, x),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::visual::{assert_golden, Recorder};
+    use std::path::Path;
+
+    #[test]
+    fn test_golden() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let input = std::fs::read_to_string(dir.join("examples/test-input.txt")).unwrap();
+
+        let mut recorder = Recorder::new(vec![], 20);
+        visualize(&input, Part::Two, &mut recorder).unwrap();
+        let frames = String::from_utf8(recorder.into_inner().unwrap()).unwrap();
+
+        assert_golden(&dir.join("golden/test-input.frames"), &frames);
+    }
+}
@@ -0,0 +1,142 @@
+//! Monkey descriptions whose worry levels stay within a `u64`.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "monkeys",
+        default: 8,
+        // Part 2 squares numbers below the product of the divisors, one
+        // prime per monkey, which must stay under 2^32
+        range: 2..=9,
+        help: "monkeys in the group",
+    },
+    Knob {
+        name: "items",
+        default: 5,
+        range: 0..=1_000,
+        help: "most items a monkey starts with",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+#[derive(Clone)]
+struct Monkey {
+    items: Vec<u64>,
+    operation: (char, Option<u64>),
+    divisor: u64,
+    targets: (usize, usize),
+}
+
+impl Monkey {
+    fn inspect(&self, item: u64) -> Option<u64> {
+        let operand = self.operation.1.unwrap_or(item);
+
+        match self.operation.0 {
+            '+' => item.checked_add(operand),
+            _ => item.checked_mul(operand),
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let count = knobs.get("monkeys");
+
+    // Retries until the 20 rounds of part 1 fit in a `u64`, dropping
+    // multiplications if that takes too long
+    let monkeys = (0..100)
+        .map(|attempt| monkeys(rng, count, knobs.get("items"), attempt < 99))
+        .find(|monkeys| fits(monkeys.clone()))
+        .expect("additions alone always fit");
+
+    let descriptions = monkeys
+        .iter()
+        .enumerate()
+        .map(|(i, monkey)| {
+            let items = monkey
+                .items
+                .iter()
+                .map(|item| format!(" {item}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            let operand = monkey
+                .operation
+                .1
+                .map_or("old".to_string(), |operand| operand.to_string());
+
+            format!(
+                "Monkey {i}:\n  Starting items:{items}\n  Operation: new = old {} {operand}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                monkey.operation.0, monkey.divisor, monkey.targets.0, monkey.targets.1
+            )
+        })
+        .collect::<Vec<_>>();
+
+    descriptions.join("\n")
+}
+
+fn monkeys(rng: &mut Rng, count: usize, items: usize, multiply: bool) -> Vec<Monkey> {
+    let mut divisors = PRIMES.to_vec();
+    let squarer = if multiply {
+        Some(rng.below(count))
+    } else {
+        None
+    };
+
+    rng.shuffle(&mut divisors);
+    (0..count)
+        .map(|i| {
+            let operation = if squarer == Some(i) {
+                ('*', None)
+            } else if multiply && rng.one_in(3) {
+                ('*', Some(rng.range(2..=19) as u64))
+            } else {
+                ('+', Some(rng.range(1..=8) as u64))
+            };
+
+            // Never to itself, and to two different monkeys when there are enough
+            let mut others = (0..count).filter(|&other| other != i).collect::<Vec<_>>();
+            rng.shuffle(&mut others);
+            let targets = match others[..] {
+                [only] => (only, only),
+                [first, second, ..] => (first, second),
+                [] => unreachable!("there are at least two monkeys"),
+            };
+
+            Monkey {
+                items: (0..rng.below(items + 1))
+                    .map(|_| rng.range(50..=99) as u64)
+                    .collect(),
+                operation,
+                divisor: divisors[i],
+                targets,
+            }
+        })
+        .collect()
+}
+
+/// Plays part 1 with checked arithmetic.
+fn fits(mut monkeys: Vec<Monkey>) -> bool {
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            for item in std::mem::take(&mut monkeys[i].items) {
+                let Some(worry) = monkeys[i].inspect(item) else {
+                    return false;
+                };
+                let worry = worry / 3;
+                let target = if worry.is_multiple_of(monkeys[i].divisor) {
+                    monkeys[i].targets.0
+                } else {
+                    monkeys[i].targets.1
+                };
+
+                monkeys[target].items.push(worry);
+            }
+        }
+    }
+
+    true
+}
@@ -0,0 +1,325 @@
+//! Day 11: Monkey in the Middle.
+//!
+//! Plays keep away with the monkeys and measures the monkey business of
+//! the two busiest ones.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_11::Day11;
+//!
+//! let monkeys = Day11::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(10605, Day11::part1(&monkeys));
+//! assert_eq!(2713310158, Day11::part2(&monkeys));
+//! ```
+
+use aoc::params::{Param, Params};
+use aoc::parse::{lines, paragraphs, Line};
+use aoc::{checkpoint, ParseError, Solution};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+pub mod generate;
+
+/// A monkey with the items it holds and how it decides where to throw them.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operand: Option<u64>,
+    operator: Operator,
+    divisible_by: u64,
+    monkey_true: usize,
+    monkey_false: usize,
+    counted: u64,
+}
+
+/// One of the busiest monkeys, as shown by `--explain`.
+#[derive(Serialize)]
+struct Busiest {
+    monkey: usize,
+    inspected: u64,
+}
+
+#[derive(Clone, Copy, Deserialize, Serialize)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(paragraph: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<Line> = lines(paragraph).collect();
+        let field = |i: usize, prefix: &str| {
+            let line = lines.get(i).copied().unwrap_or(Line::new(i, ""));
+
+            line.text
+                .strip_prefix(prefix)
+                .map(|value| (line, value))
+                .ok_or_else(|| line.error(1, format!("expected `{}`", prefix.trim())))
+        };
+        let number = |(line, value): (Line, &str)| line.token(value, "number");
+
+        field(0, "Monkey ")?;
+        let (line, values) = field(1, "  Starting items:")?;
+        let items = values
+            .split(',')
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| line.token(value, "number"))
+            .collect::<Result<_, _>>()?;
+        let (line, expression) = field(2, "  Operation: new = old ")?;
+        let (operator, operand) = expression
+            .split_once(' ')
+            .ok_or_else(|| line.error_at(expression, "expected `<operator> <operand>`"))?;
+        let operand = match operand {
+            "old" => None,
+            _ => Some(line.token(operand, "number")?),
+        };
+        let operator = match operator {
+            "+" => Operator::Add,
+            "*" => Operator::Multiply,
+            _ => return Err(line.error_at(operator, format!("unknown operator `{operator}`"))),
+        };
+        let (line, divisor) = field(3, "  Test: divisible by ")?;
+        let divisible_by: u64 = line.token(divisor, "number")?;
+        if divisible_by == 0 {
+            return Err(line.error(1, "cannot test divisibility by 0"));
+        }
+        let monkey_true = number(field(4, "    If true: throw to monkey ")?)?;
+        let monkey_false = number(field(5, "    If false: throw to monkey ")?)?;
+
+        Ok(Self {
+            items,
+            operand,
+            operator,
+            divisible_by,
+            monkey_true,
+            monkey_false,
+            counted: 0,
+        })
+    }
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let monkeys = paragraphs(input)
+        .map(|paragraph| Ok((paragraph, paragraph.parse::<Monkey>()?)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    if monkeys.len() < 2 {
+        // Points where the next monkey would start
+        return Err(ParseError::at(
+            input.lines().count(),
+            1,
+            "",
+            format!("expected at least 2 monkeys, found {}", monkeys.len()),
+        ));
+    }
+
+    for (index, (paragraph, monkey)) in monkeys.iter().enumerate() {
+        for (i, target) in [(4, monkey.monkey_true), (5, monkey.monkey_false)] {
+            let line = paragraph.lines().nth(i).unwrap_or(Line::new(0, ""));
+
+            if target >= monkeys.len() {
+                return Err(line.error(line.text.len(), format!("there is no monkey {target}")));
+            }
+            // It would catch its own items forever
+            if target == index {
+                return Err(line.error(line.text.len(), "a monkey cannot throw to itself"));
+            }
+        }
+    }
+
+    Ok(monkeys.into_iter().map(|(_, monkey)| monkey).collect())
+}
+
+/// Level of monkey business after `rounds` rounds, which is the product of
+/// the two highest numbers of items inspected. `reducer` keeps a worry
+/// level in check after each inspection.
+///
+/// ```
+/// use aoc::Solution;
+/// use day_11::Day11;
+///
+/// let (monkeys, _) = Day11::parse(include_str!("../examples/test-input.txt")).unwrap();
+///
+/// assert_eq!(10197, day_11::play_keep_away(monkeys, 20, |worry| worry % 96577));
+/// ```
+pub fn play_keep_away(monkeys: Vec<Monkey>, rounds: u32, reducer: impl Fn(u64) -> u64) -> u64 {
+    let (played, mut monkeys) = checkpoint::resume::<(u32, Vec<Monkey>)>().unwrap_or((0, monkeys));
+
+    for round in played + 1..=rounds {
+        aoc::cancel::check();
+        for i in 0..monkeys.len() {
+            monkeys[i].counted += monkeys[i].items.len() as u64;
+
+            while let Some(item) = monkeys[i].items.pop_front() {
+                let rhs = if let Some(o) = monkeys[i].operand {
+                    o
+                } else {
+                    item
+                };
+                let worry_level = reducer(match monkeys[i].operator {
+                    Operator::Add => item + rhs,
+                    Operator::Multiply => item * rhs,
+                });
+                let destination = if worry_level.is_multiple_of(monkeys[i].divisible_by) {
+                    monkeys[i].monkey_true
+                } else {
+                    monkeys[i].monkey_false
+                };
+
+                aoc::trace!(
+                    Trace,
+                    "throw",
+                    round,
+                    monkey = i,
+                    item,
+                    worry_level,
+                    to = destination
+                );
+                monkeys[destination].items.push_back(worry_level);
+            }
+        }
+        aoc::trace!(
+            Debug,
+            "round",
+            round,
+            inspected = monkeys
+                .iter()
+                .map(|monkey| monkey.counted)
+                .collect::<Vec<_>>()
+        );
+        checkpoint::save(|| (round, &monkeys));
+    }
+
+    aoc::explain::note("busiest_monkeys", || {
+        let mut busiest = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, monkey)| Busiest {
+                monkey: i,
+                inspected: monkey.counted,
+            })
+            .collect::<Vec<_>>();
+        busiest.sort_by_key(|busiest| Reverse(busiest.inspected));
+        busiest.truncate(2);
+        busiest
+    });
+    monkeys.sort_by_key(|monkey| Reverse(monkey.counted));
+    aoc::trace!(
+        Info,
+        "busiest",
+        inspected = monkeys
+            .iter()
+            .map(|monkey| monkey.counted)
+            .take(2)
+            .collect::<Vec<_>>()
+    );
+    monkeys
+        .iter()
+        .map(|monkey| monkey.counted)
+        .take(2)
+        .product()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "part1_rounds",
+            default: 20,
+            range: 0..=u32::MAX as i64,
+            help: "rounds of keep away in the first part",
+        },
+        Param {
+            name: "part2_rounds",
+            default: 10_000,
+            range: 0..=u32::MAX as i64,
+            help: "rounds of keep away in the second part",
+        },
+    ];
+
+    type Input = (Vec<Monkey>, Params);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok((parse_monkeys(input)?, params.clone()))
+    }
+
+    fn part1((monkeys, params): &Self::Input) -> u64 {
+        play_keep_away(monkeys.clone(), params.get("part1_rounds"), |item| item / 3)
+    }
+
+    fn part2((monkeys, params): &Self::Input) -> u64 {
+        let modulus: u64 = monkeys.iter().map(|monkey| monkey.divisible_by).product();
+
+        play_keep_away(monkeys.clone(), params.get("part2_rounds"), |item| {
+            item % modulus
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/test-input.txt");
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day11>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day11>(generate::GENERATOR, &[("items", 3)]);
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        aoc::checkpoint::check::<Day11>(INPUT);
+    }
+
+    #[test]
+    fn test_short_line() {
+        let input = INPUT.replacen("  Test: divisible by 23", "  Test:", 1);
+        let error = parse_monkeys(&input).err().unwrap();
+
+        assert_eq!((4, 1), (error.line, error.column));
+        assert_eq!("expected `Test: divisible by`", error.message);
+    }
+
+    #[test]
+    fn test_malformed_target() {
+        let input = INPUT.replacen(
+            "If true: throw to monkey 2",
+            "If true: throw to monkey 0",
+            1,
+        );
+        let error = parse_monkeys(&input).err().unwrap();
+
+        assert_eq!((5, 30), (error.line, error.column));
+        assert_eq!("a monkey cannot throw to itself", error.message);
+    }
+
+    #[test]
+    fn test_malformed_too_few_monkeys() {
+        let error = parse_monkeys("").err().unwrap();
+
+        assert_eq!((1, 1), (error.line, error.column));
+        assert_eq!("expected at least 2 monkeys, found 0", error.message);
+    }
+}
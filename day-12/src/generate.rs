@@ -0,0 +1,66 @@
+//! Heightmaps with a climbable path from `S` to `E` hidden among random
+//! elevations.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "width",
+        default: 160,
+        // `S` and `E` sit on opposite edges, at least 25 steps apart
+        range: 26..=5_000,
+        help: "squares in each row",
+    },
+    Knob {
+        name: "height",
+        default: 41,
+        range: 1..=5_000,
+        help: "rows of squares",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let (width, height) = (knobs.get("width"), knobs.get("height"));
+    let mut rows = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| b'a' + rng.below(26) as u8)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    // A path that only ever moves right or towards the row of `E`, so it
+    // never crosses itself
+    let (start, end) = ((0, rng.below(height)), (width - 1, rng.below(height)));
+    let mut path = vec![start];
+    let (mut x, mut y) = start;
+
+    while (x, y) != end {
+        if y != end.1 && (x == end.0 || rng.one_in(3)) {
+            y = if y < end.1 { y + 1 } else { y - 1 };
+        } else {
+            x += 1;
+        }
+        path.push((x, y));
+    }
+
+    // Climbs from `a` to `z` one step at a time, resting on a level at random
+    let mut climbs = vec![true; 25];
+    climbs.resize(path.len() - 1, false);
+    rng.shuffle(&mut climbs);
+
+    let mut elevation = b'a';
+    rows[start.1][start.0] = b'S';
+    for (&(x, y), climb) in path[1..].iter().zip(climbs) {
+        elevation += u8::from(climb);
+        rows[y][x] = elevation;
+    }
+    rows[end.1][end.0] = b'E';
+
+    rows.iter()
+        .map(|row| String::from_utf8(row.clone()).unwrap() + "\n")
+        .collect()
+}
@@ -0,0 +1,233 @@
+//! Day 12: Hill Climbing Algorithm.
+//!
+//! Finds the shortest climb to the best signal from the marked start, and
+//! from any square at the lowest elevation.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_12::Day12;
+//!
+//! let map = Day12::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(31, Day12::part1(&map));
+//! assert_eq!(29, Day12::part2(&map));
+//! ```
+
+use aoc::{ParseError, Solution};
+use grid::{Grid, Pos};
+use std::collections::VecDeque;
+
+pub mod generate;
+
+/// Elevations from `a` to `z`, with the start and goal squares resolved.
+#[derive(Clone, Debug)]
+pub struct Heightmap {
+    grid: Grid<u8>,
+    start: Pos,
+    end: Pos,
+}
+
+impl Heightmap {
+    /// Elevation of every square, with `S` and `E` resolved to `a` and `z`.
+    pub fn grid(&self) -> &Grid<u8> {
+        &self.grid
+    }
+
+    pub fn start(&self) -> Pos {
+        self.start
+    }
+
+    pub fn end(&self) -> Pos {
+        self.end
+    }
+}
+
+fn build_grid(input: &str) -> Result<Heightmap, ParseError> {
+    let mut grid = Grid::parse(input, |b| match b {
+        b'a'..=b'z' | b'S' | b'E' => Ok(b),
+        _ => Err("invalid elevation".to_string()),
+    })?;
+    let [start, end] = [b'S', b'E'].map(|marker| {
+        let count = grid.iter().filter(|&(_, &value)| value == marker).count();
+
+        match grid.find(|&value| value == marker) {
+            Some(pos) if count == 1 => Ok(pos),
+            _ => Err(ParseError::at(
+                0,
+                1,
+                input.lines().next().unwrap_or_default(),
+                format!("expected one `{}`, found {count}", marker as char),
+            )),
+        }
+    });
+    let (start, end) = (start?, end?);
+
+    grid[start] = b'a';
+    grid[end] = b'z';
+
+    // Every other start is at the same elevation, so reaching the goal from
+    // `S` is enough for both parts
+    if !reachable(&grid, start, end) {
+        let (x, y) = end;
+        return Err(ParseError::at(
+            y,
+            x + 1,
+            input.lines().nth(y).unwrap_or_default(),
+            "`E` cannot be reached from `S`",
+        ));
+    }
+
+    Ok(Heightmap { grid, start, end })
+}
+
+/// Whether `end` can be climbed to from `start`.
+fn reachable(grid: &Grid<u8>, start: Pos, end: Pos) -> bool {
+    let mut visited = grid.map(|_| false);
+    let mut stack = vec![start];
+    visited[start] = true;
+
+    while let Some(current) = stack.pop() {
+        if current == end {
+            return true;
+        }
+        for neighbor in grid.neighbors4(current) {
+            if grid[neighbor] <= grid[current] + 1 && !visited[neighbor] {
+                visited[neighbor] = true;
+                stack.push(neighbor);
+            }
+        }
+    }
+
+    false
+}
+
+/// Fewest steps from the nearest of `starts` to `end`, climbing at most
+/// one unit of elevation per step, or `None` if `end` cannot be reached.
+///
+/// ```
+/// use aoc::Solution;
+/// use day_12::Day12;
+///
+/// let map = Day12::parse(include_str!("../examples/test-input.txt")).unwrap();
+/// assert_eq!(Some(31), day_12::find_best_signal(map.grid(), [map.start()], map.end()));
+///
+/// let walled = grid::Grid::parse("azz\n", Ok).unwrap();
+/// assert_eq!(None, day_12::find_best_signal(&walled, [(0, 0)], (2, 0)));
+/// ```
+pub fn find_best_signal(
+    grid: &Grid<u8>,
+    starts: impl IntoIterator<Item = Pos>,
+    end: Pos,
+) -> Option<usize> {
+    let mut visited = grid.map(|_| false);
+    let mut queue = starts
+        .into_iter()
+        .map(|pos| (pos, 0))
+        .collect::<VecDeque<_>>();
+
+    aoc::trace!(Info, "search", starts = queue.len(), end);
+
+    while let Some((current, steps)) = queue.pop_front() {
+        if current == end {
+            aoc::trace!(Info, "reached", steps, queued = queue.len());
+            return Some(steps);
+        }
+
+        aoc::trace!(
+            Trace,
+            "expand",
+            pos = current,
+            steps,
+            elevation = grid[current] as char
+        );
+        for neighbor in grid.neighbors4(current) {
+            if grid[neighbor] <= grid[current] + 1 && !visited[neighbor] {
+                visited[neighbor] = true;
+                queue.push_back((neighbor, steps + 1));
+            }
+        }
+    }
+
+    aoc::trace!(Info, "unreachable", end);
+    None
+}
+
+fn part1(map: &Heightmap) -> usize {
+    let steps = find_best_signal(&map.grid, [map.start], map.end)
+        .expect("parse checks that the goal is reachable");
+    aoc::explain::note("path_length", || steps);
+
+    steps
+}
+
+fn part2(map: &Heightmap) -> usize {
+    let starts = map
+        .grid
+        .iter()
+        .filter_map(|(pos, &value)| (value == b'a').then_some(pos));
+    let steps = find_best_signal(&map.grid, starts, map.end)
+        .expect("parse checks that the goal is reachable");
+    aoc::explain::note("path_length", || steps);
+
+    steps
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+
+    type Input = Heightmap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        build_grid(input)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        part1(map)
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        part2(map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day12>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_malformed_unreachable_goal() {
+        let error = build_grid("Sbc\nxyE\n").err().unwrap();
+
+        assert_eq!((2, 3), (error.line, error.column));
+        assert_eq!("`E` cannot be reached from `S`", error.message);
+    }
+
+    #[test]
+    fn test_explain() {
+        let map = Day12::parse(include_str!("../examples/test-input.txt")).unwrap();
+
+        assert_eq!(
+            "path length: 31\n",
+            aoc::explain::explain(|| Day12::part1(&map)).1.to_string()
+        );
+        assert_eq!(
+            "path length: 29\n",
+            aoc::explain::explain(|| Day12::part2(&map)).1.to_string()
+        );
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day12>(generate::GENERATOR, &[("width", 40), ("height", 10)]);
+    }
+}
@@ -0,0 +1,124 @@
+//! Pairs of nested packets, often alike so the comparison has to look deep.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+use std::fmt;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "pairs",
+        default: 150,
+        range: 1..=1_000_000,
+        help: "pairs of packets",
+    },
+    Knob {
+        name: "depth",
+        default: 4,
+        range: 0..=100,
+        help: "most lists nested inside a packet",
+    },
+    Knob {
+        name: "length",
+        default: 5,
+        range: 0..=100,
+        help: "most values in a single list",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+#[derive(Clone)]
+enum Packet {
+    Number(usize),
+    List(Vec<Packet>),
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Packet::Number(number) => write!(f, "{number}"),
+            Packet::List(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+struct Shape {
+    depth: usize,
+    length: usize,
+}
+
+impl Shape {
+    fn list(&self, rng: &mut Rng, depth: usize) -> Packet {
+        Packet::List(
+            (0..rng.below(self.length + 1))
+                .map(|_| self.value(rng, depth))
+                .collect(),
+        )
+    }
+
+    fn value(&self, rng: &mut Rng, depth: usize) -> Packet {
+        if depth < self.depth && rng.one_in(3) {
+            self.list(rng, depth + 1)
+        } else {
+            Packet::Number(rng.below(11))
+        }
+    }
+
+    /// A copy of `packet` with one value changed somewhere inside, so the
+    /// two compare equal up to that point.
+    fn variant(&self, rng: &mut Rng, packet: &Packet, depth: usize) -> Packet {
+        match packet {
+            Packet::List(values) if !values.is_empty() && !rng.one_in(4) => {
+                let i = rng.below(values.len());
+                let mut copy = values[..i].to_vec();
+
+                copy.push(self.variant(rng, &values[i], depth + 1));
+                if rng.one_in(2) {
+                    copy.extend_from_slice(&values[i + 1..]);
+                }
+                Packet::List(copy)
+            }
+            Packet::List(_) => self.list(rng, depth),
+            // Comparing a number with a list holding it is a case of its own
+            Packet::Number(number) if rng.one_in(2) => Packet::List(vec![Packet::Number(*number)]),
+            Packet::Number(_) => self.value(rng, depth),
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let shape = Shape {
+        depth: knobs.get("depth"),
+        length: knobs.get("length"),
+    };
+    let pairs = (0..knobs.get("pairs"))
+        .map(|_| loop {
+            let left = shape.list(rng, 0);
+            let right = if rng.one_in(2) {
+                shape.variant(rng, &left, 0)
+            } else {
+                shape.list(rng, 0)
+            };
+            let pair = format!("{left}\n{right}\n");
+
+            // The dividers must stay unique for part 2
+            if !pair
+                .lines()
+                .any(|packet| packet == "[[2]]" || packet == "[[6]]")
+            {
+                break pair;
+            }
+        })
+        .collect::<Vec<_>>();
+
+    pairs.join("\n")
+}
@@ -0,0 +1,176 @@
+//! Day 13: Distress Signal.
+//!
+//! Compares pairs of packets and sorts them all along with the two divider
+//! packets.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_13::Day13;
+//!
+//! let packets = Day13::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(13, Day13::part1(&packets));
+//! assert_eq!(140, Day13::part2(&packets));
+//! ```
+
+use aoc::parse::paragraphs;
+use aoc::{ParseError, Solution};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+pub mod generate;
+
+/// Order of two packets: integers compare as numbers, lists item by item
+/// with the shorter list first, and an integer facing a list is compared
+/// as a list holding just that integer.
+///
+/// # Panics
+///
+/// If either value is not a packet, see [`Day13::parse`].
+///
+/// ```
+/// use serde_json::json;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(Ordering::Less, day_13::compare(&json!([1, 1, 3]), &json!([1, 1, 5])));
+/// assert_eq!(Ordering::Greater, day_13::compare(&json!([9]), &json!([[8, 7, 6]])));
+/// assert_eq!(Ordering::Equal, day_13::compare(&json!([[2]]), &json!(2)));
+/// ```
+pub fn compare(left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        (Value::Array(a_array), Value::Array(b_array)) => {
+            for i in 0..a_array.len().max(b_array.len()) {
+                match (a_array.get(i), b_array.get(i)) {
+                    (None, _) => return Ordering::Less,
+                    (_, None) => return Ordering::Greater,
+                    (Some(a_value), Some(b_value)) => match compare(a_value, b_value) {
+                        Ordering::Equal => {}
+                        ordering => return ordering,
+                    },
+                }
+            }
+
+            Ordering::Equal
+        }
+        (Value::Number(a_number), Value::Number(b_number)) => {
+            a_number.as_u64().unwrap().cmp(&b_number.as_u64().unwrap())
+        }
+        (Value::Number(_), Value::Array(_)) => compare(&Value::Array(vec![left.clone()]), right),
+        (Value::Array(_), Value::Number(_)) => compare(left, &Value::Array(vec![right.clone()])),
+        _ => unreachable!(),
+    }
+}
+
+/// Checks that a packet only holds lists and non-negative integers.
+fn is_packet(value: &Value) -> bool {
+    match value {
+        Value::Array(values) => values.iter().all(is_packet),
+        Value::Number(number) => number.is_u64(),
+        _ => false,
+    }
+}
+
+fn parse_packets(input: &str) -> Result<Vec<Value>, ParseError> {
+    let mut packets = vec![];
+
+    for pair in paragraphs(input) {
+        let lines = pair.lines().collect::<Vec<_>>();
+
+        if lines.len() != 2 {
+            // Point at the first packet too many, or the one without a pair
+            let line = lines.get(2).unwrap_or(&lines[0]);
+
+            return Err(line.error(
+                1,
+                format!("expected a pair of packets, found {}", lines.len()),
+            ));
+        }
+
+        for line in lines {
+            let packet = serde_json::from_str::<Value>(line.text).map_err(|error| {
+                line.error(error.column().max(1), format!("invalid packet: {error}"))
+            })?;
+
+            if !matches!(packet, Value::Array(_)) || !is_packet(&packet) {
+                return Err(line.error(1, "packets must be lists of lists and integers"));
+            }
+
+            packets.push(packet);
+        }
+    }
+
+    Ok(packets)
+}
+
+fn part1(packets: &[Value]) -> usize {
+    let ordered = packets
+        .chunks(2)
+        .enumerate()
+        .filter(|(i, pair)| {
+            let ordering = compare(&pair[0], &pair[1]);
+            aoc::trace!(Debug, "pair", index = i + 1, ordering);
+            ordering == Ordering::Less
+        })
+        .map(|(i, _)| i + 1)
+        .collect::<Vec<_>>();
+    aoc::explain::note("ordered_pairs", || &ordered);
+
+    ordered.iter().sum()
+}
+
+fn part2(packets: &[Value]) -> usize {
+    let mut packets = packets.to_vec();
+    let dividers: Vec<_> = ["[[2]]", "[[6]]"]
+        .iter()
+        .map(|packet| serde_json::from_str::<Value>(packet).unwrap())
+        .collect();
+
+    packets.extend(dividers.clone());
+    packets.sort_by(compare);
+
+    let indices = (1..=packets.len())
+        .filter(|&i| dividers.contains(&packets[i - 1]))
+        .inspect(|&index| aoc::trace!(Info, "divider", index))
+        .collect::<Vec<_>>();
+    aoc::explain::note("divider_indices", || &indices);
+
+    indices.iter().product()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Distress Signal";
+
+    type Input = Vec<Value>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_packets(input)
+    }
+
+    fn part1(packets: &Self::Input) -> usize {
+        part1(packets)
+    }
+
+    fn part2(packets: &Self::Input) -> usize {
+        part2(packets)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day13>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day13>(generate::GENERATOR, &[]);
+    }
+}
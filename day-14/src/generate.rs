@@ -0,0 +1,61 @@
+//! Scans of rock paths made of horizontal and vertical segments.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "paths",
+        default: 150,
+        range: 1..=100_000,
+        help: "rock paths in the scan",
+    },
+    Knob {
+        name: "points",
+        default: 6,
+        range: 2..=1_000,
+        help: "most points on a single path",
+    },
+    Knob {
+        name: "spread",
+        default: 60,
+        // Keeps every x coordinate positive
+        range: 1..=490,
+        help: "furthest a rock lies left or right of the source",
+    },
+    Knob {
+        name: "depth",
+        default: 170,
+        range: 1..=10_000,
+        help: "deepest a rock lies below the source",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let (spread, depth) = (knobs.get("spread") as i64, knobs.get("depth") as i64);
+
+    (0..knobs.get("paths"))
+        .map(|_| {
+            let mut point = (rng.range(500 - spread..=500 + spread), rng.range(1..=depth));
+            let mut path = vec![point];
+
+            for _ in 1..2 + rng.below(knobs.get("points") - 1) {
+                // Short segments, like the scans of the puzzle
+                if rng.one_in(2) {
+                    point.0 = (point.0 + rng.range(-8..=8)).clamp(500 - spread, 500 + spread);
+                } else {
+                    point.1 = (point.1 + rng.range(-8..=8)).clamp(1, depth);
+                }
+                path.push(point);
+            }
+
+            let path = path
+                .iter()
+                .map(|(x, y)| format!("{x},{y}"))
+                .collect::<Vec<_>>();
+            path.join(" -> ") + "\n"
+        })
+        .collect()
+}
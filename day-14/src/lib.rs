@@ -0,0 +1,270 @@
+//! Day 14: Regolith Reservoir.
+//!
+//! Pours sand into the cave until it falls into the abyss, then again onto
+//! the floor until it blocks the source.
+//!
+//! ```
+//! use aoc::Solution;
+//! use day_14::Day14;
+//!
+//! let cave = Day14::parse(include_str!("../examples/test-input.txt")).unwrap();
+//!
+//! assert_eq!(24, Day14::part1(&cave));
+//! assert_eq!(93, Day14::part2(&cave));
+//! ```
+
+use aoc::params::{Param, Params};
+use aoc::parse::{lines, Line};
+use aoc::{checkpoint, ParseError, Part, Solution};
+use grid::{Pos as Coord, SparseGrid};
+
+pub mod generate;
+pub mod reference;
+pub mod visual;
+
+/// What fills each square of the cave, with `y` growing downwards.
+pub type Map = SparseGrid<Tile>;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Tile {
+    Rock,
+    Sand,
+}
+
+fn build_map(input: &str) -> Result<(Map, usize), ParseError> {
+    let mut map = Map::new();
+    let mut max_y = 0;
+
+    for line in lines(input) {
+        let path: Vec<Coord> = line.path()?;
+
+        for window in path.windows(2) {
+            let (x_start, y_start) = window[0];
+            let (x_end, y_end) = window[1];
+
+            if x_start != x_end && y_start != y_end {
+                return Err(line.error(1, "rock segments must be horizontal or vertical"));
+            }
+
+            (x_start.min(x_end)..=x_start.max(x_end)).for_each(|x| {
+                (y_start.min(y_end)..=y_start.max(y_end)).for_each(|y| {
+                    if y > max_y {
+                        max_y = y
+                    }
+
+                    map.insert((x, y), Tile::Rock);
+                })
+            })
+        }
+    }
+
+    Ok((map, max_y))
+}
+
+/// Drops sand from `source` until `stop_condition` holds, calling
+/// `on_rest` with the map whenever a unit of sand comes to rest.
+///
+/// Snapshots hold the sand at rest, since the rocks come from the input.
+fn simulate(
+    map: &mut Map,
+    source: Coord,
+    stop_condition: impl Fn(Coord) -> bool,
+    on_the_floor: impl Fn(usize) -> bool,
+    mut on_rest: impl FnMut(&Map, Coord),
+) {
+    for coord in checkpoint::resume::<Vec<Coord>>().unwrap_or_default() {
+        map.insert(coord, Tile::Sand);
+    }
+
+    loop {
+        let (mut x, mut y) = (source.0 as isize, source.1);
+
+        loop {
+            // A cave that never meets `stop_condition` would pour forever
+            aoc::cancel::check();
+            match [x, x - 1, x + 1]
+                .iter()
+                .find(|&&next_x| !map.contains((next_x as usize, y + 1)) && on_the_floor(y + 1))
+            {
+                Some(&next_x) => {
+                    x = next_x;
+                    y += 1;
+                }
+                None => {
+                    if map.insert((x as usize, y), Tile::Sand).is_none() {
+                        aoc::trace!(Debug, "rest", x, y);
+                        on_rest(map, (x as usize, y));
+                        checkpoint::save(|| {
+                            map.iter()
+                                .filter(|(_, &tile)| tile == Tile::Sand)
+                                .map(|(&coord, _)| coord)
+                                .collect::<Vec<_>>()
+                        });
+                        break;
+                    }
+                }
+            };
+
+            if stop_condition((x as usize, y)) {
+                return;
+            }
+        }
+    }
+}
+
+/// The map once sand stops flowing for `part`, calling `on_rest` as
+/// `simulate` does.
+fn pour(
+    (map, max_y, source): &(Map, usize, Coord),
+    part: Part,
+    on_rest: impl FnMut(&Map, Coord),
+) -> Map {
+    let (mut map, max_y, source) = (map.clone(), *max_y, *source);
+
+    match part {
+        // Sand also stops once it piles up to the source, if rocks hold it all
+        Part::One => simulate(
+            &mut map,
+            source,
+            |coord| coord.1 > max_y || coord == source,
+            |_| true,
+            on_rest,
+        ),
+        Part::Two => simulate(
+            &mut map,
+            source,
+            |coord| coord == source,
+            |y| y < max_y + 2,
+            on_rest,
+        ),
+    }
+
+    map
+}
+
+fn count_sand(map: &Map) -> usize {
+    map.values().filter(|&&tile| tile == Tile::Sand).count()
+}
+
+fn part1(cave: &(Map, usize, Coord)) -> usize {
+    count_sand(&pour(cave, Part::One, |_, _| {}))
+}
+
+fn part2(cave: &(Map, usize, Coord)) -> usize {
+    count_sand(&pour(cave, Part::Two, |_, _| {}))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "source_x",
+            default: 500,
+            range: 1..=1_000_000,
+            help: "column the sand pours in from",
+        },
+        Param {
+            name: "source_y",
+            default: 0,
+            range: 0..=1_000_000,
+            help: "row the sand pours in from",
+        },
+    ];
+
+    /// The rocks, the depth of the lowest one and the source of the sand.
+    type Input = (Map, usize, Coord);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let (map, max_y) = build_map(input)?;
+        let source: Coord = (params.get("source_x"), params.get("source_y"));
+
+        // The pile on the floor spreads one column further left each row
+        // down, and the map has no room left of x = 0
+        if source.0 + source.1 <= max_y {
+            let first = lines(input).next().unwrap_or(Line::new(0, ""));
+            return Err(first.error(
+                1,
+                format!(
+                    "sand from `source_x` = {} would pile up past x = 0",
+                    source.0
+                ),
+            ));
+        }
+
+        Ok((map, max_y, source))
+    }
+
+    fn part1(cave: &Self::Input) -> usize {
+        part1(cave)
+    }
+
+    fn part2(cave: &Self::Input) -> usize {
+        part2(cave)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::generate::Knobs;
+    use aoc::rng::Rng;
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day14>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day14>(generate::GENERATOR, &[("paths", 20), ("depth", 30)]);
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        aoc::checkpoint::check::<Day14>(include_str!("../examples/test-input.txt"));
+    }
+
+    #[test]
+    fn test_malformed_source() {
+        let input = include_str!("../examples/test-input.txt");
+        let params = Params::new(Day14::PARAMS, &[("source_x", 9)]).unwrap();
+        let error = Day14::parse_with(input, &params).err().unwrap();
+
+        assert_eq!((1, 1), (error.line, error.column));
+        assert_eq!(
+            "sand from `source_x` = 9 would pile up past x = 0",
+            error.message
+        );
+
+        // Away from the rocks, the pile fills a triangle down to x = 0
+        let params = Params::new(Day14::PARAMS, &[("source_x", 10)]).unwrap();
+        let cave = Day14::parse_with(input, &params).unwrap();
+        assert_eq!(11 * 11, Day14::part2(&cave));
+    }
+
+    #[test]
+    fn test_reference() {
+        let small = |rng: &mut Rng| {
+            let knobs = [
+                ("paths", 1 + rng.below(4)),
+                ("points", 4),
+                ("spread", 6),
+                ("depth", 10),
+            ];
+            generate::generate(rng, &Knobs::new(generate::KNOBS, &knobs).unwrap())
+        };
+        let naive = |input: &str| (reference::part1(input), reference::part2(input));
+
+        aoc::reference::check::<Day14, _, _>(&[], small, naive);
+    }
+}
@@ -0,0 +1,90 @@
+//! Naive solver to check the optimized one against.
+//!
+//! Keeps the cave as a plain set of blocked cells and drops every grain
+//! from the source one cell at a time.
+
+use std::collections::HashSet;
+
+const SOURCE: (i64, i64) = (500, 0);
+
+fn parse(input: &str) -> (HashSet<(i64, i64)>, i64) {
+    let mut rocks = HashSet::new();
+
+    for line in input.lines() {
+        let points = line
+            .split(" -> ")
+            .map(|point| {
+                let (x, y) = point.split_once(',').unwrap();
+                (x.parse::<i64>().unwrap(), y.parse::<i64>().unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        for pair in points.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+
+            for x in x1.min(x2)..=x1.max(x2) {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    rocks.insert((x, y));
+                }
+            }
+        }
+    }
+
+    let lowest = rocks.iter().map(|&(_, y)| y).max().unwrap_or(0);
+    (rocks, lowest)
+}
+
+/// Where a grain from the source comes to rest, or `None` once it falls
+/// below `abyss`.
+fn drop_grain(blocked: &HashSet<(i64, i64)>, floor: Option<i64>, abyss: i64) -> Option<(i64, i64)> {
+    let (mut x, mut y) = SOURCE;
+
+    loop {
+        if y > abyss {
+            return None;
+        }
+
+        let free = |cell: (i64, i64)| !blocked.contains(&cell) && Some(cell.1) != floor;
+
+        if free((x, y + 1)) {
+            y += 1;
+        } else if free((x - 1, y + 1)) {
+            x -= 1;
+            y += 1;
+        } else if free((x + 1, y + 1)) {
+            x += 1;
+            y += 1;
+        } else {
+            return Some((x, y));
+        }
+    }
+}
+
+pub fn part1(input: &str) -> usize {
+    let (mut blocked, lowest) = parse(input);
+    let mut grains = 0;
+
+    while !blocked.contains(&SOURCE) {
+        let Some(cell) = drop_grain(&blocked, None, lowest) else {
+            break;
+        };
+        blocked.insert(cell);
+        grains += 1;
+    }
+
+    grains
+}
+
+pub fn part2(input: &str) -> usize {
+    let (mut blocked, lowest) = parse(input);
+    let floor = lowest + 2;
+    let mut grains = 0;
+
+    while !blocked.contains(&SOURCE) {
+        let cell = drop_grain(&blocked, Some(floor), floor).expect("the floor stops every grain");
+        blocked.insert(cell);
+        grains += 1;
+    }
+
+    grains
+}
@@ -0,0 +1,77 @@
+//! Sand piling up in the cave one unit at a time.
+
+use crate::{count_sand, pour, Day14, Map, Tile};
+use aoc::visual::Viewer;
+use aoc::{ParseError, Part, Solution};
+use grid::Pos;
+
+/// The cave from the source down to the lowest rock, or to the floor for
+/// the second part, with one empty column on each side.
+fn draw(map: &Map, max_y: usize, source: Pos, part: Part) -> String {
+    let ((min_x, _), (max_x, _)) = map.bounds().unwrap_or((source, source));
+    let (min_x, max_x) = (
+        min_x.min(source.0).saturating_sub(1),
+        max_x.max(source.0) + 1,
+    );
+    let floor = match part {
+        Part::One => None,
+        Part::Two => Some(max_y + 2),
+    };
+
+    (0..=floor.unwrap_or(max_y))
+        .map(|y| {
+            (min_x..=max_x)
+                .map(|x| match map.get((x, y)) {
+                    Some(Tile::Rock) => '#',
+                    Some(Tile::Sand) => 'o',
+                    None if Some(y) == floor => '#',
+                    None if (x, y) == source => '+',
+                    None => '.',
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Offers a frame whenever a unit of sand comes to rest.
+pub fn visualize(input: &str, part: Part, viewer: &mut dyn Viewer) -> Result<(), ParseError> {
+    let cave = Day14::parse(input)?;
+    let (_, max_y, source) = cave;
+
+    let mut units = 0;
+    let map = pour(&cave, part, |map, (x, y)| {
+        units += 1;
+        viewer.frame(format_args!("{units} units, last at {x},{y}"), &|| {
+            draw(map, max_y, source, part)
+        });
+    });
+
+    viewer.finish(format_args!("{} units at rest", count_sand(&map)), &|| {
+        draw(&map, max_y, source, part)
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::visual::{assert_golden, Recorder};
+    use std::path::Path;
+
+    #[test]
+    fn test_golden() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let input = std::fs::read_to_string(dir.join("examples/test-input.txt")).unwrap();
+
+        for (part, every) in [(Part::One, 4), (Part::Two, 16)] {
+            let mut recorder = Recorder::new(vec![], every);
+            visualize(&input, part, &mut recorder).unwrap();
+            let frames = String::from_utf8(recorder.into_inner().unwrap()).unwrap();
+
+            let golden = format!("golden/test-input-part{part}.frames");
+            assert_golden(&dir.join(golden), &frames);
+        }
+    }
+}
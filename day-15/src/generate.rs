@@ -0,0 +1,94 @@
+//! Sensor layouts that leave exactly one cell of the search area uncovered.
+//!
+//! Sensors of equal range on a diagonal lattice cover the whole area, like
+//! tiles. Those that would reach the hidden cell are cut short or dropped,
+//! and four sensors placed diagonally out from the hidden cell cover what
+//! that leaves, reaching right up to it but never onto it.
+
+use aoc::generate::{Generator, Knob, Knobs};
+use aoc::rng::Rng;
+
+pub const KNOBS: &[Knob] = &[
+    Knob {
+        name: "sensors",
+        default: 30,
+        range: 1..=100_000,
+        help: "roughly how many sensors cover the area",
+    },
+    Knob {
+        name: "area",
+        default: 4_000_000,
+        range: 1..=1_000_000_000,
+        help: "highest x and y of the search area",
+    },
+];
+
+pub const GENERATOR: Generator = Generator::new(KNOBS, generate);
+
+type Point = (i64, i64);
+
+fn distance((x1, y1): Point, (x2, y2): Point) -> i64 {
+    (x1 - x2).abs() + (y1 - y2).abs()
+}
+
+/// Sensors of range `range` whose centers sit on the lattice spanned by
+/// `(range, range)` and `(range, -range)`, which covers every cell, keeping
+/// only those that reach into the area.
+fn lattice(area: i64, range: i64) -> Vec<Point> {
+    let outside = |(x, y): Point| (-x).max(x - area).max(0) + (-y).max(y - area).max(0);
+    let (lowest, highest) = (-area / (2 * range) - 2, area / range + 2);
+
+    (lowest..=highest)
+        .flat_map(|i| (lowest..=highest).map(move |j| (range * (i + j), range * (i - j))))
+        .filter(|&center| outside(center) <= range)
+        .collect()
+}
+
+pub fn generate(rng: &mut Rng, knobs: &Knobs) -> String {
+    let area = knobs.get("area") as i64;
+    let range = ((area as f64 / (knobs.get("sensors") as f64).sqrt()) as i64).max(1);
+    let hidden = (rng.range(0..=area), rng.range(0..=area));
+    let mut sensors = vec![];
+
+    // Cutting a sensor short only uncovers cells within `range` of it, so
+    // within `2 * range` of the hidden cell
+    for (sx, sy) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+        let reach = 2 * range + rng.range(0..=range);
+        let sensor = (hidden.0 + sx * reach, hidden.1 + sy * reach);
+
+        // Covers the square of side `reach` between it and the hidden cell
+        sensors.push((sensor, 2 * reach - 1, (hidden.0 + sx, hidden.1)));
+    }
+
+    for center in lattice(area, range) {
+        let cut = range.min(distance(center, hidden) - 1);
+
+        if cut > 0 && sensors.iter().all(|&(sensor, _, _)| sensor != center) {
+            sensors.push((center, cut, center));
+        }
+    }
+
+    // Beacons go anywhere on the edge of their sensor's range, as long as
+    // no cell ends up with both a sensor and a beacon
+    for i in 4..sensors.len() {
+        let (center, cut, _) = sensors[i];
+
+        sensors[i].2 = loop {
+            let dx = rng.range(-cut..=cut);
+            let dy = (cut - dx.abs()) * if rng.one_in(2) { 1 } else { -1 };
+            let beacon = (center.0 + dx, center.1 + dy);
+
+            if sensors.iter().all(|&(sensor, _, _)| sensor != beacon) {
+                break beacon;
+            }
+        };
+    }
+    rng.shuffle(&mut sensors);
+
+    sensors
+        .iter()
+        .map(|((sx, sy), _, (bx, by))| {
+            format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n")
+        })
+        .collect()
+}
@@ -0,0 +1,331 @@
+//! Day 15: Beacon Exclusion Zone.
+//!
+//! Uses the sensor reports to rule out positions of the distress beacon.
+//! The puzzle asks about row 2000000 and an area of 4000000, while the
+//! example asks about row 10 and an area of 20, so it is parsed with those
+//! [parameters](aoc::params).
+//!
+//! ```
+//! use aoc::params::Params;
+//! use aoc::Solution;
+//! use day_15::Day15;
+//!
+//! let params = Params::new(Day15::PARAMS, &[("row", 10), ("max", 20)]).unwrap();
+//! let sensors = Day15::parse_with(include_str!("../examples/test-input.txt"), &params).unwrap();
+//!
+//! assert_eq!(26, Day15::part1(&sensors));
+//! assert_eq!(56000011, Day15::part2(&sensors));
+//! ```
+
+use aoc::params::{Param, Params};
+use aoc::parse::{lines, Line};
+use aoc::{ParseError, Solution};
+use std::collections::{BTreeMap, HashSet};
+
+pub mod generate;
+pub mod reference;
+
+/// Sensors, with their distance to the closest beacon, and the beacons.
+pub type Map = BTreeMap<Coord, Tile>;
+pub type Coord = (isize, isize);
+
+#[derive(Clone, Copy)]
+pub enum Tile {
+    Sensor(isize),
+    Beacon,
+}
+
+/// Distance between two positions moving only along the axes.
+///
+/// ```
+/// assert_eq!(5, day_15::manhattan_distance(&(0, 0), &(2, -3)));
+/// ```
+pub fn manhattan_distance((x1, y1): &Coord, (x2, y2): &Coord) -> isize {
+    (x1 - x2).abs() + (y1 - y2).abs()
+}
+
+fn build_map(input: &str) -> Result<Map, ParseError> {
+    let mut map = Map::new();
+
+    for line in lines(input) {
+        let [sx, sy, bx, by] = line.integers_n::<isize, 4>("coordinates")?;
+        map.insert(
+            (sx, sy),
+            Tile::Sensor(manhattan_distance(&(sx, sy), &(bx, by))),
+        );
+        map.insert((bx, by), Tile::Beacon);
+    }
+
+    Ok(map)
+}
+
+/// Whether some position with both coordinates between 0 and `max_xy` is
+/// out of reach of every sensor.
+///
+/// With `u = x + y` and `v = x - y`, each sensor covers a square. The free
+/// position with the smallest `u`, then the smallest `v`, has its
+/// neighbors before it outside the area or just inside a square, so it
+/// lies where two edges of those cross.
+fn has_uncovered(map: &Map, max_xy: isize) -> bool {
+    let sensors = map
+        .iter()
+        .filter_map(|(&(x, y), tile)| match tile {
+            Tile::Sensor(distance) => Some((x as i128, y as i128, *distance as i128)),
+            Tile::Beacon => None,
+        })
+        .collect::<Vec<_>>();
+    let max = max_xy as i128;
+
+    let mut us = vec![];
+    let mut vs = vec![];
+    for &(x, y, distance) in &sensors {
+        us.extend([x + y + distance + 1, x + y + distance + 2]);
+        vs.extend([
+            x - y + distance + 1,
+            x - y + distance + 2,
+            x - y - distance - 1,
+        ]);
+    }
+    // `x = 0`, `y = 0` and `y = max` as `v = sign * u + offset`
+    let edges = [(-1, 0), (1, 0), (1, -2 * max)];
+
+    let mut candidates = vec![(0, 0), (max, -max)];
+    for &u in &us {
+        candidates.extend(vs.iter().map(|&v| (u, v)));
+        candidates.extend(edges.map(|(sign, offset)| (u, sign * u + offset)));
+    }
+    for &v in &vs {
+        candidates.extend(edges.map(|(sign, offset)| (sign * (v - offset), v)));
+    }
+
+    candidates.into_iter().any(|(u, v)| {
+        let (x, y) = ((u + v) / 2, (u - v) / 2);
+
+        (u + v).rem_euclid(2) == 0
+            && (0..=max).contains(&x)
+            && (0..=max).contains(&y)
+            && sensors
+                .iter()
+                .all(|&(sx, sy, distance)| (x - sx).abs() + (y - sy).abs() > distance)
+    })
+}
+
+/// Number of positions in `row` where no beacon can be.
+pub fn part1(map: &Map, row: isize) -> usize {
+    let mut no_beacon = HashSet::new();
+
+    map.iter()
+        .filter(|(_, tile)| matches!(tile, Tile::Sensor(..)))
+        .for_each(|(&(sx, sy), tile)| {
+            aoc::cancel::check();
+            if let Tile::Sensor(beacon_distance) = tile {
+                let row_distance = (sy - row).abs();
+                let delta_distance = beacon_distance - row_distance;
+                aoc::trace!(Debug, "sensor", at = (sx, sy), reach = delta_distance);
+
+                for x in (sx - delta_distance)..=(sx + delta_distance) {
+                    // A sensor's own cell cannot hold a beacon either
+                    if !matches!(map.get(&(x, row)), Some(Tile::Beacon)) {
+                        no_beacon.insert((x, row));
+                    }
+                }
+            }
+        });
+
+    no_beacon.len()
+}
+
+/// Tuning frequency of the one position, with both coordinates between 0
+/// and `max_xy`, that no sensor covers: its `x` times `multiplier`, plus
+/// its `y`.
+///
+/// # Panics
+///
+/// If every position is covered.
+pub fn part2(map: &Map, max_xy: isize, multiplier: isize) -> isize {
+    let signals: Vec<(Coord, isize)> = map
+        .iter()
+        .filter_map(|(coord, tile)| match tile {
+            Tile::Sensor(distance) => Some((*coord, *distance)),
+            _ => None,
+        })
+        .collect();
+
+    for y in 0..=max_xy {
+        aoc::cancel::check();
+        aoc::trace!(Trace, "row", y);
+        let mut x = 0;
+
+        'x_loop: while x <= max_xy {
+            for ((sx, sy), beacon_distance) in &signals {
+                let distance = manhattan_distance(&(x, y), &(*sx, *sy));
+
+                if distance > *beacon_distance {
+                    continue;
+                }
+
+                let y_distance = (sy - y).abs();
+                let delta_distance = beacon_distance - y_distance;
+                let max_x = sx + delta_distance;
+
+                x = max_x + 1;
+
+                continue 'x_loop;
+            }
+
+            // Outside all sensors coverage
+            aoc::trace!(Info, "uncovered", x, y);
+            return multiplier * x + y;
+        }
+    }
+
+    panic!("every position up to `max` = {max_xy} is covered");
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "row",
+            default: 2_000_000,
+            range: i64::MIN..=i64::MAX,
+            help: "row to count the positions without a beacon in",
+        },
+        Param {
+            name: "max",
+            default: 4_000_000,
+            range: 0..=i64::MAX,
+            help: "largest coordinate of the distress beacon",
+        },
+        Param {
+            name: "multiplier",
+            default: 4_000_000,
+            range: 0..=i64::MAX,
+            help: "what x is multiplied by in the tuning frequency",
+        },
+    ];
+
+    type Input = (Map, Params);
+    type Part1 = usize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::defaults(Self::PARAMS))
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let map = build_map(input)?;
+        let max_xy = params.get("max");
+        if !has_uncovered(&map, max_xy) {
+            let first = lines(input).next().unwrap_or(Line::new(0, ""));
+            return Err(first.error(
+                1,
+                format!("every position up to `max` = {max_xy} is in reach of a sensor"),
+            ));
+        }
+
+        Ok((map, params.clone()))
+    }
+
+    fn part1((map, params): &Self::Input) -> usize {
+        part1(map, params.get("row"))
+    }
+
+    fn part2((map, params): &Self::Input) -> isize {
+        part2(map, params.get("max"), params.get("multiplier"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use aoc::generate::Knobs;
+    use aoc::rng::Rng;
+
+    const INPUT: &str = include_str!("../examples/test-input.txt");
+
+    #[test]
+    fn test_examples() {
+        aoc::examples::check::<Day15>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_generate() {
+        aoc::generate::check::<Day15>(generate::GENERATOR, &[("area", 20)]);
+    }
+
+    #[test]
+    fn test_generate_one_uncovered_cell() {
+        for seed in 0..8 {
+            let input = generate::GENERATOR.run(seed, &[("area", 20)]).unwrap();
+            let map = build_map(&input).unwrap();
+
+            assert_eq!(1, count_uncovered(&map, 20), "seed {seed}");
+        }
+    }
+
+    #[test]
+    fn test_has_uncovered() {
+        for seed in 0..32 {
+            let knobs = [("sensors", 1 + seed as usize % 8), ("area", 20)];
+            let input = generate::GENERATOR.run(seed, &knobs).unwrap();
+            let map = build_map(&input).unwrap();
+
+            for max_xy in 0..=24 {
+                assert_eq!(
+                    count_uncovered(&map, max_xy) > 0,
+                    has_uncovered(&map, max_xy),
+                    "seed {seed}, max {max_xy}"
+                );
+            }
+        }
+    }
+
+    fn count_uncovered(map: &Map, max_xy: isize) -> usize {
+        (0..=max_xy)
+            .flat_map(|y| (0..=max_xy).map(move |x| (x, y)))
+            .filter(|cell| {
+                map.iter().all(|(sensor, tile)| match tile {
+                    Tile::Sensor(range) => manhattan_distance(sensor, cell) > *range,
+                    Tile::Beacon => true,
+                })
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_reference() {
+        let small = |rng: &mut Rng| {
+            let knobs = [("sensors", 4 + rng.below(4)), ("area", 20)];
+            generate::generate(rng, &Knobs::new(generate::KNOBS, &knobs).unwrap())
+        };
+        let naive = |input: &str| (reference::part1(input, 10), reference::part2(input, 20));
+
+        aoc::reference::check::<Day15, _, _>(&[("row", 10), ("max", 20)], small, naive);
+    }
+
+    #[test]
+    fn test_malformed_line() {
+        let input = INPUT.replacen(", y=15", "", 1);
+        let error = build_map(&input).err().unwrap();
+
+        assert_eq!(1, error.line);
+        assert_eq!("expected 4 coordinates, found 3", error.message);
+    }
+
+    #[test]
+    fn test_malformed_max() {
+        let params = Params::new(Day15::PARAMS, &[("max", 10)]).unwrap();
+        let error = Day15::parse_with(INPUT, &params).err().unwrap();
+
+        assert_eq!((1, 1), (error.line, error.column));
+        assert_eq!(
+            "every position up to `max` = 10 is in reach of a sensor",
+            error.message
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Naive solver to check the optimized one against.
+//!
+//! Tries every cell of the row, or of the whole search area, against every
+//! sensor, so it is only usable on small inputs.
+
+type Point = (i64, i64);
+
+/// Sensors with their closest beacon.
+fn parse(input: &str) -> Vec<(Point, Point)> {
+    input
+        .lines()
+        .map(|line| {
+            let numbers = line
+                .split(|ch: char| !(ch.is_ascii_digit() || ch == '-'))
+                .filter(|token| !token.is_empty())
+                .map(|token| token.parse().unwrap())
+                .collect::<Vec<i64>>();
+            ((numbers[0], numbers[1]), (numbers[2], numbers[3]))
+        })
+        .collect()
+}
+
+fn distance((x1, y1): Point, (x2, y2): Point) -> i64 {
+    (x1 - x2).abs() + (y1 - y2).abs()
+}
+
+fn covered(sensors: &[(Point, Point)], cell: Point) -> bool {
+    sensors
+        .iter()
+        .any(|&(sensor, beacon)| distance(sensor, cell) <= distance(sensor, beacon))
+}
+
+/// Cells of `row` that cannot hold a beacon.
+pub fn part1(input: &str, row: i64) -> usize {
+    let sensors = parse(input);
+    let reach = sensors
+        .iter()
+        .map(|&(sensor, beacon)| distance(sensor, beacon))
+        .max()
+        .unwrap_or(0);
+    let left = sensors
+        .iter()
+        .map(|((x, _), _)| x - reach)
+        .min()
+        .unwrap_or(0);
+    let right = sensors
+        .iter()
+        .map(|((x, _), _)| x + reach)
+        .max()
+        .unwrap_or(0);
+
+    (left..=right)
+        .filter(|&x| covered(&sensors, (x, row)))
+        .filter(|&x| sensors.iter().all(|&(_, beacon)| beacon != (x, row)))
+        .count()
+}
+
+/// Tuning frequency of the first cell in reading order, between 0 and
+/// `max_xy`, that no sensor covers.
+pub fn part2(input: &str, max_xy: i64) -> i64 {
+    let sensors = parse(input);
+
+    for y in 0..=max_xy {
+        for x in 0..=max_xy {
+            if !covered(&sensors, (x, y)) {
+                return 4_000_000 * x + y;
+            }
+        }
+    }
+
+    panic!("every cell is covered");
+}
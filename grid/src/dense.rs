@@ -0,0 +1,222 @@
+use crate::{around, step, Offset, Pos, ALL_DIRECTIONS, ORTHOGONAL};
+use aoc::parse::lines;
+use aoc::ParseError;
+use std::ops::{Index, IndexMut};
+
+/// Rectangle of cells stored row by row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` by `height` cells, all set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses one cell per byte and one row per line, so every line must
+    /// have the same length. `cell` rejects a byte by returning a message.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(u8) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+
+        for line in lines(input) {
+            let width = *width.get_or_insert(line.text.len());
+
+            if line.text.len() != width {
+                return Err(line.error(
+                    line.text.len().min(width) + 1,
+                    format!("expected {width} columns, found {}", line.text.len()),
+                ));
+            }
+            for (x, &byte) in line.text.as_bytes().iter().enumerate() {
+                cells.push(cell(byte).map_err(|message| line.error(x + 1, message))?);
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::at(0, 1, "", "empty grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.1 * self.width + pos.0])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell, row by row, matching `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..][..self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Cells from `pos`, exclusive, to the edge of the grid in the direction
+    /// of `offset`.
+    pub fn ray(&self, pos: Pos, offset: Offset) -> impl Iterator<Item = (Pos, &T)> {
+        std::iter::successors(step(pos, offset), move |&pos| step(pos, offset))
+            .map_while(|pos| Some((pos, self.get(pos)?)))
+    }
+
+    /// Orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        around(pos, &ORTHOGONAL).filter(|&pos| self.contains(pos))
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos` inside the grid.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        around(pos, &ALL_DIRECTIONS).filter(|&pos| self.contains(pos))
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// One line per row, without a trailing newline.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                text.push('\n');
+            }
+            text.extend(row.iter().map(&mut cell));
+        }
+
+        text
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{DOWN, LEFT};
+
+    fn digits(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse(input, |byte| match byte {
+            b'0'..=b'9' => Ok(byte - b'0'),
+            _ => Err("expected a digit".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = digits("123\n456\n").unwrap();
+
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(6, grid[(2, 1)]);
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!("123\n456", grid.render(|&d| (b'0' + d) as char));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            ParseError::at(1, 2, "4x6", "expected a digit"),
+            digits("123\n4x6").unwrap_err()
+        );
+        assert_eq!(
+            ParseError::at(1, 3, "45", "expected 3 columns, found 2"),
+            digits("123\n45").unwrap_err()
+        );
+        assert!(digits("").is_err());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = digits("123\n456\n789").unwrap();
+        let cells = |it: &mut dyn Iterator<Item = &u8>| it.copied().collect::<Vec<_>>();
+
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(vec![2, 5, 8], cells(&mut grid.column(1)));
+        assert_eq!(
+            vec![6, 9],
+            cells(&mut grid.ray((2, 0), DOWN).map(|(_, d)| d))
+        );
+        assert_eq!(0, grid.ray((0, 1), LEFT).count());
+        assert_eq!(Some((1, 1)), grid.find(|&d| d == 5));
+        assert_eq!(2, grid.neighbors4((0, 0)).count());
+        assert_eq!(8, grid.neighbors8((1, 1)).count());
+    }
+}
@@ -0,0 +1,56 @@
+//! Two-dimensional grids shared by the puzzles laid out on a map.
+//!
+//! [`Grid`] stores every cell of a rectangle, [`SparseGrid`] only the
+//! occupied ones. Both address cells by `(x, y)`, with `y` growing
+//! downwards as in the puzzle text.
+
+mod dense;
+mod sparse;
+
+pub use dense::Grid;
+pub use sparse::SparseGrid;
+
+/// Cell position as `(x, y)`.
+pub type Pos = (usize, usize);
+
+/// Step between two cells as `(dx, dy)`.
+pub type Offset = (isize, isize);
+
+pub const UP: Offset = (0, -1);
+pub const DOWN: Offset = (0, 1);
+pub const LEFT: Offset = (-1, 0);
+pub const RIGHT: Offset = (1, 0);
+
+/// The four orthogonal steps.
+pub const ORTHOGONAL: [Offset; 4] = [UP, DOWN, LEFT, RIGHT];
+
+/// The orthogonal steps followed by the four diagonal ones.
+pub const ALL_DIRECTIONS: [Offset; 8] = [UP, DOWN, LEFT, RIGHT, (-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+/// `pos` moved by `offset`, or `None` when it would leave the first quadrant.
+pub fn step((x, y): Pos, (dx, dy): Offset) -> Option<Pos> {
+    Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+}
+
+/// Positions around `pos` in the given directions, skipping those with a
+/// negative coordinate.
+fn around(pos: Pos, directions: &'static [Offset]) -> impl Iterator<Item = Pos> {
+    directions
+        .iter()
+        .filter_map(move |&offset| step(pos, offset))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        assert_eq!(Some((1, 0)), step((1, 1), UP));
+        assert_eq!(None, step((0, 1), LEFT));
+        assert_eq!(
+            vec![(0, 1), (1, 0), (1, 1)],
+            around((0, 0), &ALL_DIRECTIONS).collect::<Vec<_>>()
+        );
+    }
+}
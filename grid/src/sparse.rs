@@ -0,0 +1,153 @@
+use crate::{around, Pos, ALL_DIRECTIONS, ORTHOGONAL};
+use aoc::parse::lines;
+use aoc::ParseError;
+use std::collections::btree_map::{self, BTreeMap};
+
+/// Unbounded grid that only stores its occupied cells.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: BTreeMap<Pos, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: BTreeMap::new(),
+        }
+    }
+
+    /// Parses one cell per byte and one row per line. `cell` returns `None`
+    /// for an empty cell and rejects a byte by returning a message.
+    pub fn parse(
+        input: &str,
+        mut cell: impl FnMut(u8) -> Result<Option<T>, String>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = Self::new();
+
+        for line in lines(input) {
+            for (x, &byte) in line.text.as_bytes().iter().enumerate() {
+                match cell(byte) {
+                    Ok(Some(value)) => grid.insert((x, line.index), value),
+                    Ok(None) => None,
+                    Err(message) => return Err(line.error(x + 1, message)),
+                };
+            }
+        }
+
+        Ok(grid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    /// Stores `value` at `pos`, returning the value it replaced.
+    pub fn insert(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// Occupied cells ordered by `x`, then `y`.
+    pub fn iter(&self) -> btree_map::Iter<'_, Pos, T> {
+        self.cells.iter()
+    }
+
+    pub fn values(&self) -> btree_map::Values<'_, Pos, T> {
+        self.cells.values()
+    }
+
+    /// Smallest and largest corner enclosing every occupied cell.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.cells.keys().fold(None, |bounds, &(x, y)| {
+            let ((min_x, min_y), (max_x, max_y)) = bounds.unwrap_or(((x, y), (x, y)));
+
+            Some(((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))))
+        })
+    }
+
+    /// Orthogonal neighbours of `pos`, occupied or not.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        around(pos, &ORTHOGONAL)
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos`, occupied or not.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        around(pos, &ALL_DIRECTIONS)
+    }
+
+    /// The [`bounds`](Self::bounds) as text, one line per row, with `empty`
+    /// for the unoccupied cells.
+    pub fn render(&self, empty: char, mut cell: impl FnMut(&T) -> char) -> String {
+        let Some(((min_x, min_y), (max_x, max_y))) = self.bounds() else {
+            return String::new();
+        };
+
+        (min_y..=max_y)
+            .map(|y| {
+                (min_x..=max_x)
+                    .map(|x| self.get((x, y)).map_or(empty, &mut cell))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Pos, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Pos, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = SparseGrid::parse("..#\n#..", |byte| match byte {
+            b'#' => Ok(Some(())),
+            b'.' => Ok(None),
+            _ => Err("unknown tile".to_string()),
+        })
+        .unwrap();
+
+        assert_eq!(2, grid.len());
+        assert!(grid.contains((2, 0)));
+        assert_eq!(Some(((0, 0), (2, 1))), grid.bounds());
+        assert_eq!("..#\n#..", grid.render('.', |_| '#'));
+    }
+
+    #[test]
+    fn test_render_bounds() {
+        let grid = [((500, 4), 'o'), ((502, 5), '#')]
+            .into_iter()
+            .collect::<SparseGrid<_>>();
+
+        assert_eq!("o..\n..#", grid.render('.', |&c| c));
+        assert_eq!("", SparseGrid::<char>::new().render('.', |&c| c));
+    }
+}